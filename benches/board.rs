@@ -0,0 +1,102 @@
+//! Compares `Board` lookups against a linear scan of its tiles,
+//! on late-game boards built by playing full games.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use qwirkle_solver::{
+    board::{location::Location, position::Coordinate, Board},
+    game::Game,
+    player::moves::get_combination_moves,
+    rules::ruleset::RuleSet,
+    tile::Tile,
+};
+
+const GAMES: usize = 5;
+const ROUNDS: usize = 20;
+
+/// Lookup as done before `Board` was indexed by position.
+fn linear_get(board: &Board, x: Coordinate, y: Coordinate) -> Option<Tile> {
+    board
+        .tiles()
+        .iter()
+        .find(|Location { position, .. }| position.x == x && position.y == y)
+        .map(|location| location.tile)
+}
+
+/// Plays a full game from `seed` and returns its final board.
+fn late_game_board(seed: u64) -> Board {
+    let mut game = Game::with_seed(4, seed);
+    while game.step().is_some() {}
+
+    game.board().clone()
+}
+
+/// Returns the bounds `(left, right, bottom, top)` of `board`, with a margin of one position.
+fn bounds(board: &Board) -> (Coordinate, Coordinate, Coordinate, Coordinate) {
+    let xs = board.tiles().iter().map(|location| location.position.x);
+    let ys = board.tiles().iter().map(|location| location.position.y);
+
+    (
+        xs.clone().min().unwrap_or(0) - 1,
+        xs.max().unwrap_or(0) + 1,
+        ys.clone().min().unwrap_or(0) - 1,
+        ys.max().unwrap_or(0) + 1,
+    )
+}
+
+/// Looks up every position within `board` bounds, `ROUNDS` times.
+fn time_lookups(
+    board: &Board,
+    get: impl Fn(&Board, Coordinate, Coordinate) -> Option<Tile>,
+) -> Duration {
+    let (left, right, bottom, top) = bounds(board);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for x in left..=right {
+            for y in bottom..=top {
+                black_box(get(board, x, y));
+            }
+        }
+    }
+
+    start.elapsed()
+}
+
+/// Generates moves for every tile of the game, `ROUNDS` times.
+fn time_moves(board: &Board) -> Duration {
+    let combinations = board
+        .tiles()
+        .iter()
+        .map(|location| vec![location.tile])
+        .collect::<Vec<_>>();
+
+    let rules = RuleSet::default();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for combination in combinations.iter() {
+            black_box(get_combination_moves(&rules, board, combination));
+        }
+    }
+
+    start.elapsed()
+}
+
+fn main() {
+    let boards = (0..GAMES as u64).map(late_game_board).collect::<Vec<_>>();
+
+    for (index, board) in boards.iter().enumerate() {
+        let linear = time_lookups(board, linear_get);
+        let indexed = time_lookups(board, |board, x, y| board.get(x, y));
+        let moves = time_moves(board);
+
+        println!(
+            "board {index} ({} tiles): linear lookups {linear:?}, indexed lookups {indexed:?} (x{:.1}), move generation {moves:?}",
+            board.tiles().len(),
+            linear.as_secs_f64() / indexed.as_secs_f64(),
+        );
+    }
+}
@@ -1,29 +1,65 @@
-use std::fmt::Debug;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use std::fmt::{self, Debug, Formatter};
 
-use crate::tile::{Color, Shape, Tile, Tiles, COLORS_NUMBER, SHAPES_NUMBER, SHAPES_REPETITION};
+use crate::rules::ruleset::RuleSet;
+use crate::tile::{Tile, Tiles};
+use crate::tile_set::TileSet;
 
 /// 6 shapes * 6 colors * 3 tiles = 108 tiles
 pub const BAG_SIZE: usize = 108;
 
-#[derive(Debug)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Bag {
     // ? TODO: find a way to define maximum length?
     tiles: Tiles,
+    /// Every draw and addition, to take them back with `undo()`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    changes: Vec<Change>,
+}
+
+/// A change of the bag, see `Bag::undo()`.
+#[derive(Debug, Clone, Copy)]
+enum Change {
+    /// The tile was drawn at this index, the last tile taking its place.
+    Drawn(usize, Tile),
+    /// A tile was added at the end.
+    Added,
+}
+
+/// Returned by `Bag::checkpoint()`, to take back later changes with `Bag::undo()`.
+#[must_use]
+pub(crate) struct BagToken {
+    /// How many changes were made before the checkpoint.
+    changes_number: usize,
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Bag {
-    /// Constructs a new filled bag with 108 tiles. Tiles are sorted.
+    /// Constructs a new filled bag with 108 tiles,
+    /// i.e. 3 copies of each color and shape pair. Tiles are sorted.
     pub fn new() -> Bag {
-        let mut tiles = Vec::new();
+        Bag::from_tiles(TileSet::full().tiles())
+    }
 
-        for index in 0..BAG_SIZE {
-            tiles.push(Tile {
-                color: get_tile_color(index),
-                shape: get_tile_shape(index),
-            });
-        }
+    /// Constructs a new bag filled with every tile of a game played with `rules`.
+    /// Tiles are sorted.
+    pub fn with_rules(rules: &RuleSet) -> Bag {
+        Bag::from_tiles(rules.tile_set().tiles())
+    }
 
-        Bag { tiles }
+    /// Constructs a bag holding exactly `tiles`, e.g. to resume a saved game.
+    pub fn from_tiles(tiles: Tiles) -> Bag {
+        Bag {
+            tiles,
+            changes: Vec::new(),
+        }
     }
 
     /// Returns all tiles in the bag.
@@ -31,41 +67,92 @@ impl Bag {
         &self.tiles
     }
 
-    /// Add back `tiles` to the bag.
+    /// Returns how many tiles are left in the bag.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Returns `true` if there is no tile left in the bag.
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Returns how many copies of `tile` are left in the bag.
+    pub fn count(&self, tile: Tile) -> usize {
+        self.tiles.iter().filter(|&&t| t == tile).count()
+    }
+
+    /// Shuffles tiles within the bag.
+    /// Changes made until now can't be taken back anymore.
+    pub fn shuffle(&mut self, rng: &mut StdRng) {
+        self.tiles.shuffle(rng);
+        self.changes.clear();
+    }
+
+    /// Draws `number` random tiles from the bag.
+    /// If the bag holds fewer tiles, every remaining tile is drawn.
+    pub fn draw(&mut self, number: usize, rng: &mut StdRng) -> Tiles {
+        let number = number.min(self.tiles.len());
+
+        (0..number)
+            .map(|_| {
+                let index = rng.random_range(0..self.tiles.len());
+                let tile = self.tiles.swap_remove(index);
+                self.changes.push(Change::Drawn(index, tile));
+
+                tile
+            })
+            .collect()
+    }
+
+    /// Draws as many random tiles as `tiles`, and only then puts `tiles` back in the bag.
+    /// Returns drawn tiles.
+    pub fn exchange(&mut self, tiles: Tiles, rng: &mut StdRng) -> Tiles {
+        let drawn = self.draw(tiles.len(), rng);
+        self.add(tiles);
+
+        drawn
+    }
+
+    /// Adds back `tiles` to the bag.
     pub fn add(&mut self, tiles: Tiles) {
+        self.changes.extend(tiles.iter().map(|_| Change::Added));
         self.tiles.extend(tiles);
     }
 
-    /// Removes and returns the tile at position `index` within the bag.
-    pub fn remove(&mut self, index: usize) -> Option<Tile> {
-        if index < self.tiles.len() {
-            Some(self.tiles.remove(index))
-        } else {
-            None
+    /// Returns a token to take back every change made from now on, see `undo()`.
+    pub(crate) fn checkpoint(&self) -> BagToken {
+        BagToken {
+            changes_number: self.changes.len(),
         }
     }
-}
 
-fn get_tile_color(index: usize) -> Color {
-    match (index / (COLORS_NUMBER * 3)) % COLORS_NUMBER {
-        0 => Color::Red,
-        1 => Color::Orange,
-        2 => Color::Yellow,
-        3 => Color::Green,
-        4 => Color::Blue,
-        5 => Color::Purple,
-        _ => Color::Red,
+    /// Takes back changes made since `token` was returned by `checkpoint()`,
+    /// putting tiles back in their exact order.
+    /// Tokens must be undone in reverse order, the last returned being the first undone.
+    pub(crate) fn undo(&mut self, token: BagToken) {
+        let changes = self
+            .changes
+            .split_off(token.changes_number.min(self.changes.len()));
+
+        for &change in changes.iter().rev() {
+            match change {
+                Change::Drawn(index, tile) => {
+                    // reverse of `swap_remove()`: the tile at `index` goes back at the end
+                    self.tiles.push(tile);
+                    let last = self.tiles.len() - 1;
+                    self.tiles.swap(index, last);
+                }
+                Change::Added => {
+                    self.tiles.pop();
+                }
+            }
+        }
     }
 }
 
-fn get_tile_shape(index: usize) -> Shape {
-    match (index / SHAPES_REPETITION) % SHAPES_NUMBER {
-        0 => Shape::Square,
-        1 => Shape::Circle,
-        2 => Shape::Diamond,
-        3 => Shape::Club,
-        4 => Shape::Star,
-        5 => Shape::Cross,
-        _ => Shape::Square,
+impl Debug for Bag {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Bag").field("tiles", &self.tiles).finish()
     }
 }
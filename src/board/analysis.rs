@@ -0,0 +1,247 @@
+//! What can still happen on a board: lines, what they miss and where they can grow,
+//! for bots to spot Qwirkle threats and for a UI to give hints.
+//!
+//! Tiles which can still be placed are the ones of `RuleSet::tile_set()`
+//! which aren't on the board yet, wherever they are, in the bag or in a hand.
+
+use std::fmt::{self, Debug, Formatter};
+
+use crate::rules::{ruleset::RuleSet, validate_placement};
+use crate::tile::{Color, Shape, Tile, Tiles};
+use crate::tile_set::TileSet;
+
+use super::{
+    direction::Direction, location::Location, placement::Placement, position::Position, Board,
+};
+
+/// What every tile of a line shares.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Attribute {
+    Color(Color),
+    Shape(Shape),
+}
+
+impl Debug for Attribute {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Attribute::Color(color) => write!(f, "{color}"),
+            Attribute::Shape(shape) => write!(f, "{shape}"),
+        }
+    }
+}
+
+/// A free position next to an extremity of a line.
+#[derive(Clone, PartialEq, Eq)]
+pub struct End {
+    pub position: Position,
+    /// Tiles left which can be placed at `position`, alone, without breaking any line.
+    pub tiles: Tiles,
+}
+
+impl End {
+    /// Returns `true` if a tile left can still be placed at this end.
+    pub fn is_fillable(&self) -> bool {
+        !self.tiles.is_empty()
+    }
+}
+
+impl Debug for End {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Position { x, y } = self.position;
+        write!(f, "{x},{y} {:?}", self.tiles)
+    }
+}
+
+/// A maximal line of at least two tiles.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Line {
+    /// Tiles of the line, from west to east or from south to north.
+    pub locations: Vec<Location>,
+    /// Either east or north, following `locations`.
+    pub direction: Direction,
+    pub attribute: Attribute,
+    /// Tiles sharing `attribute` which aren't in the line yet.
+    pub missing: Tiles,
+    /// Tiles of `missing` which can still be placed.
+    pub missing_left: Tiles,
+    /// Free positions at both extremities, in `direction` order.
+    /// Extremities out of range aren't ends.
+    pub ends: Vec<End>,
+}
+
+impl Line {
+    /// Returns how many tiles the line holds.
+    pub fn len(&self) -> usize {
+        self.locations.len()
+    }
+
+    /// Returns `true` if the line holds no tile, which never happens for analysed lines.
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    /// Returns `true` if the line is a Qwirkle.
+    pub fn is_complete(&self, rules: &RuleSet) -> bool {
+        self.len() >= rules.qwirkle_length
+    }
+
+    /// Returns `true` if the line can't become a Qwirkle anymore,
+    /// because too few of its missing tiles are left.
+    pub fn is_dead(&self, rules: &RuleSet) -> bool {
+        !self.is_complete(rules) && self.len() + self.missing_left.len() < rules.qwirkle_length
+    }
+
+    /// Returns `true` if a single tile left can complete the line into a Qwirkle.
+    pub fn is_qwirkle_threat(&self, rules: &RuleSet) -> bool {
+        self.len() + 1 == rules.qwirkle_length && self.ends.iter().any(End::is_fillable)
+    }
+}
+
+impl Debug for Line {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Line {
+            locations,
+            attribute,
+            missing_left,
+            ends,
+            ..
+        } = self;
+        write!(
+            f,
+            "{attribute:?} line {locations:?}, missing {missing_left:?}, ends {ends:?}"
+        )
+    }
+}
+
+/// Returns tiles of `rules` which aren't on `board` yet.
+pub fn tiles_left(rules: &RuleSet, board: &Board) -> TileSet {
+    let mut left = rules.tile_set();
+    for location in board.tiles() {
+        left.remove(location.tile);
+    }
+
+    left
+}
+
+/// Returns every maximal line of at least two tiles on `board`,
+/// in the order their first tile was placed, horizontal lines first.
+pub fn lines(rules: &RuleSet, board: &Board) -> Vec<Line> {
+    let left = tiles_left(rules, board);
+
+    let mut lines = Vec::new();
+    for &location in board.tiles() {
+        for direction in [Direction(1, 0), Direction(0, 1)] {
+            // only lines starting at this location
+            let before = location.position.step(direction.opposite(), 1);
+            if before.is_some_and(|position| board.get(position.x, position.y).is_some()) {
+                continue;
+            }
+
+            let mut locations = vec![location];
+            locations.extend(board.get_locations(location.position, direction));
+            if locations.len() > 1 {
+                lines.push(analyse_line(rules, board, &left, locations, direction));
+            }
+        }
+    }
+
+    lines
+}
+
+/// Returns free positions next to tiles of `board` where no tile left can be placed.
+pub fn unfillable_positions(rules: &RuleSet, board: &Board) -> Vec<Position> {
+    let left = tiles_left(rules, board);
+
+    let mut positions = Vec::new();
+    for location in board.tiles() {
+        for direction in Direction::values() {
+            let Some(position) = location.position.step(direction, 1) else {
+                continue;
+            };
+            let is_free = position.is_in_range() && board.get(position.x, position.y).is_none();
+            if is_free
+                && !positions.contains(&position)
+                && fitting_tiles(rules, board, &left, position).is_empty()
+            {
+                positions.push(position);
+            }
+        }
+    }
+
+    positions
+}
+
+/// Builds the line made of `locations`, in `direction`.
+fn analyse_line(
+    rules: &RuleSet,
+    board: &Board,
+    left: &TileSet,
+    locations: Vec<Location>,
+    direction: Direction,
+) -> Line {
+    let (first, second) = (locations[0].tile, locations[1].tile);
+    let attribute = if first.color == second.color {
+        Attribute::Color(first.color)
+    } else {
+        Attribute::Shape(first.shape)
+    };
+
+    let candidates = match attribute {
+        Attribute::Color(color) => rules
+            .shapes()
+            .into_iter()
+            .map(|shape| Tile { color, shape })
+            .collect::<Tiles>(),
+        Attribute::Shape(shape) => rules
+            .colors()
+            .into_iter()
+            .map(|color| Tile { color, shape })
+            .collect(),
+    };
+    let missing = candidates
+        .into_iter()
+        .filter(|tile| !locations.iter().any(|location| location.tile == *tile))
+        .collect::<Tiles>();
+    let missing_left = missing
+        .iter()
+        .copied()
+        .filter(|&tile| left.count(tile) > 0)
+        .collect();
+
+    let first = locations[0].position.step(direction.opposite(), 1);
+    let last = locations[locations.len() - 1].position.step(direction, 1);
+    let ends = [first, last]
+        .into_iter()
+        .flatten()
+        .filter(|position| position.is_in_range())
+        .map(|position| End {
+            position,
+            tiles: fitting_tiles(rules, board, left, position),
+        })
+        .collect();
+
+    Line {
+        locations,
+        direction,
+        attribute,
+        missing,
+        missing_left,
+        ends,
+    }
+}
+
+/// Returns tiles of `left` which can be placed alone at the free `position`.
+fn fitting_tiles(rules: &RuleSet, board: &Board, left: &TileSet, position: Position) -> Tiles {
+    let mut tiles = left.tiles();
+    tiles.dedup();
+
+    tiles
+        .into_iter()
+        .filter(|&tile| {
+            let placement = Placement {
+                locations: vec![Location { position, tile }],
+            };
+            validate_placement(rules, board, &placement).is_ok()
+        })
+        .collect()
+}
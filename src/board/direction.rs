@@ -1,12 +1,17 @@
-use rand::Rng;
-use std::fmt::{Debug, Formatter, Result};
+use rand::{rngs::StdRng, Rng};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::notation::NotationError;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "String", try_from = "String"))]
 pub struct Direction(pub i8, pub i8);
 
 impl Direction {
-    pub fn rand() -> Direction {
-        let index = rand::rng().random_range(0..4);
+    pub fn rand(rng: &mut StdRng) -> Direction {
+        let index = rng.random_range(0..4);
         match index {
             0 => Direction(0, 1),  // north ^
             1 => Direction(1, 0),  // east >
@@ -32,19 +37,18 @@ impl Direction {
 
     pub fn perpendicular(&self) -> Direction {
         let Direction(x, y) = *self;
-        let right_angle = match (x, y) {
+        match (x, y) {
             (0, 1) => Direction(1, 0),   // north to east
             (1, 0) => Direction(0, -1),  // east to south
             (0, -1) => Direction(-1, 0), // south to west
             (-1, 0) => Direction(0, 1),  // west to north
             _ => Direction(0, 1),
-        };
-        right_angle
+        }
     }
 }
 
 impl Debug for Direction {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let Direction(x, y) = self;
         let character = match (x, y) {
             (0, 1) => "^",
@@ -56,3 +60,54 @@ impl Debug for Direction {
         write!(f, "{character}")
     }
 }
+
+impl Display for Direction {
+    /// Writes the direction as an arrow, e.g. `>` for east, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl FromStr for Direction {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "^" => Ok(Direction(0, 1)),
+            ">" => Ok(Direction(1, 0)),
+            "v" => Ok(Direction(0, -1)),
+            "<" => Ok(Direction(-1, 0)),
+            _ => Err(NotationError::Direction(text.to_owned())),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Direction> for String {
+    /// Names the direction, e.g. `north`, to serialize it.
+    fn from(direction: Direction) -> Self {
+        let name = match direction {
+            Direction(0, 1) => "north",
+            Direction(1, 0) => "east",
+            Direction(0, -1) => "south",
+            Direction(-1, 0) => "west",
+            _ => "",
+        };
+        name.to_owned()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<String> for Direction {
+    type Error = NotationError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        match name.as_str() {
+            "north" => Ok(Direction(0, 1)),
+            "east" => Ok(Direction(1, 0)),
+            "south" => Ok(Direction(0, -1)),
+            "west" => Ok(Direction(-1, 0)),
+            _ => Err(NotationError::Direction(name)),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::{Debug, Display, Formatter, Result};
+
+use super::position::Position;
+
+/// Why a tile can't be added to the board.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BoardError {
+    /// A tile is already placed at this position.
+    Occupied(Position),
+    /// This position is beyond `Position::MIN` or `Position::MAX`.
+    OutOfRange(Position),
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            BoardError::Occupied(position) => write!(f, "position {position:?} already taken"),
+            BoardError::OutOfRange(position) => write!(f, "position {position:?} out of range"),
+        }
+    }
+}
+
+impl Debug for BoardError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "BoardError({self})")
+    }
+}
+
+impl std::error::Error for BoardError {}
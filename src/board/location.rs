@@ -1,17 +1,20 @@
-use std::fmt::{Debug, Formatter, Result};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
 
+use crate::notation::NotationError;
 use crate::tile::Tile;
 
 use super::position::Position;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     pub position: Position,
     pub tile: Tile,
 }
 
 impl Debug for Location {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let Location {
             position: Position { x, y },
             tile,
@@ -19,3 +22,26 @@ impl Debug for Location {
         write!(f, "{tile:?} ({x}, {y})")
     }
 }
+
+impl Display for Location {
+    /// Writes the location in notation, e.g. `yS@0,0`, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Location { position, tile } = self;
+        write!(f, "{tile}@{position}")
+    }
+}
+
+impl FromStr for Location {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (tile, position) = text
+            .split_once('@')
+            .ok_or_else(|| NotationError::Location(text.to_owned()))?;
+
+        Ok(Location {
+            position: position.parse()?,
+            tile: tile.parse()?,
+        })
+    }
+}
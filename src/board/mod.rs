@@ -1,26 +1,51 @@
+pub mod analysis;
 pub mod direction;
+pub mod error;
 pub mod location;
 pub mod r#move;
+pub mod placement;
 pub mod position;
+pub mod undo;
 
-use std::fmt::{Debug, Formatter, Result};
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
 
 use direction::Direction;
+use error::BoardError;
 use location::Location;
-use position::Position;
-use r#move::PartialMove;
+use placement::Placement;
+use position::{Coordinate, Position};
+use r#move::{Move, PartialMove};
+use undo::UndoToken;
 
 use crate::tile::{Tile, Tiles};
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "Vec<Location>", try_from = "Vec<Location>")
+)]
 pub struct Board {
+    /// Tiles in the order they were placed.
     tiles: Vec<Location>,
+    /// Same tiles indexed by position, for fast lookups.
+    index: HashMap<Position, Tile>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Board {
     /// Constructs a new, empty board to play on.
     pub fn new() -> Board {
-        Board { tiles: Vec::new() }
+        Board {
+            tiles: Vec::new(),
+            index: HashMap::new(),
+        }
     }
 
     /// Returns all tiles on the board. Tiles aren't sorted.
@@ -28,71 +53,151 @@ impl Board {
         &self.tiles
     }
 
-    // TODO: check if location x/y is free before pushing
     /// Adds a tile to the board at `(x, y)` position.
-    pub fn add_tile(&mut self, location: Location) {
+    /// Fails if the position is already taken or out of range.
+    pub fn add_tile(&mut self, location: Location) -> Result<(), BoardError> {
+        self.check_free(location.position)?;
+
         self.tiles.push(location);
+        self.index.insert(location.position, location.tile);
+
+        Ok(())
     }
 
     /// Adds multiple tiles from a `partial_move`.
-    /// Starts at `position`, goes to `direction` and places tiles from `combination`.
-    pub fn add_tiles(&mut self, partial_move: &PartialMove) {
-        let PartialMove {
-            combination,
-            position,
-            direction,
-        } = partial_move;
-
-        for (index, &tile) in combination.iter().enumerate() {
-            let step = index as i8;
-            let location = Location {
-                tile,
-                position: Position {
-                    x: position.x + direction.0 * step,
-                    y: position.y + direction.1 * step,
-                },
-            };
-            self.add_tile(location)
+    /// Starts at `position`, goes to `direction` and places tiles from `combination`,
+    /// stepping over tiles already on the board.
+    /// If any tile can't be placed, nothing is added.
+    pub fn add_tiles(&mut self, partial_move: &PartialMove) -> Result<(), BoardError> {
+        let placement = partial_move.placement(self)?;
+        self.add_placement(&placement)
+    }
+
+    /// Adds every tile of a `placement` at its own position.
+    /// If any tile can't be placed, nothing is added.
+    pub fn add_placement(&mut self, placement: &Placement) -> Result<(), BoardError> {
+        for (index, location) in placement.locations.iter().enumerate() {
+            self.check_free(location.position)?;
+
+            let is_duplicate = placement.locations[..index]
+                .iter()
+                .any(|previous| previous.position == location.position);
+            if is_duplicate {
+                return Err(BoardError::Occupied(location.position));
+            }
+        }
+
+        for &location in placement.locations.iter() {
+            self.add_tile(location)?;
         }
+
+        Ok(())
     }
 
-    /// Searches for a tile at `(x, y)` position.
-    /// If something is found `Some(Tile)` is returned, otherwise`None`.
-    pub fn get(&self, x: i8, y: i8) -> Option<Tile> {
-        let location = self
-            .tiles
-            .iter()
-            .find(|Location { position, .. }| position.x == x && position.y == y);
+    /// Places tiles of `move`, as `add_tiles()` does,
+    /// and returns a token to take them back with `undo()`.
+    /// If any tile can't be placed, nothing is added.
+    pub fn apply(&mut self, r#move: &Move) -> Result<UndoToken, BoardError> {
+        let token = self.checkpoint();
+        let (partial_move, _) = r#move.clone().into_partial();
+        self.add_tiles(&partial_move)?;
+
+        Ok(token)
+    }
+
+    /// Removes tiles placed since `token` was returned by `apply()`.
+    /// Tokens must be undone in reverse order, the last returned being the first undone.
+    pub fn undo(&mut self, token: UndoToken) {
+        for location in self.tiles.drain(token.tiles_number..) {
+            self.index.remove(&location.position);
+        }
+    }
+
+    /// Returns a token to remove every tile placed from now on, see `undo()`.
+    pub(crate) fn checkpoint(&self) -> UndoToken {
+        UndoToken {
+            tiles_number: self.tiles.len(),
+        }
+    }
+
+    /// Checks that a tile can be placed at `position`.
+    fn check_free(&self, position: Position) -> Result<(), BoardError> {
+        if !position.is_in_range() {
+            Err(BoardError::OutOfRange(position))
+        } else if self.index.contains_key(&position) {
+            Err(BoardError::Occupied(position))
+        } else {
+            Ok(())
+        }
+    }
 
-        let tile = match location {
-            Some(location) => Some(location.tile),
-            None => None,
-        };
+    /// Returns the first free position after `position` in `direction`,
+    /// or `None` if it can't be represented.
+    pub fn next_free_position(&self, position: Position, direction: Direction) -> Option<Position> {
+        let mut next = position;
+        loop {
+            next = next.step(direction, 1)?;
+            if self.get(next.x, next.y).is_none() {
+                return Some(next);
+            }
+        }
+    }
 
-        tile
+    /// Searches for a tile at `(x, y)` position.
+    /// If something is found `Some(Tile)` is returned, otherwise`None`.
+    pub fn get(&self, x: Coordinate, y: Coordinate) -> Option<Tile> {
+        self.index.get(&Position { x, y }).copied()
     }
 
     /// Returns tiles next to a given position, for a given direction,
     /// until an empty location is reached.
     pub fn get_tiles(&self, position: Position, direction: Direction) -> Tiles {
-        let mut tiles = Vec::new();
+        self.get_locations(position, direction)
+            .iter()
+            .map(|location| location.tile)
+            .collect()
+    }
+
+    /// Same as `get_tiles()`, but keeps the position of each tile.
+    pub fn get_locations(&self, position: Position, direction: Direction) -> Vec<Location> {
+        let mut locations = Vec::new();
 
         let mut step = 1;
-        while let Some(tile) = self.get(
-            position.x + direction.0 * step,
-            position.y + direction.1 * step,
-        ) {
-            tiles.push(tile);
+        while let Some(position) = position.step(direction, step) {
+            let Some(tile) = self.get(position.x, position.y) else {
+                break;
+            };
+            locations.push(Location { position, tile });
 
             step += 1;
         }
 
-        tiles
+        locations
+    }
+}
+
+impl TryFrom<Vec<Location>> for Board {
+    type Error = BoardError;
+
+    /// Places `locations` in order on a new board.
+    fn try_from(locations: Vec<Location>) -> Result<Self, Self::Error> {
+        let mut board = Board::new();
+        for location in locations {
+            board.add_tile(location)?;
+        }
+
+        Ok(board)
+    }
+}
+
+impl From<Board> for Vec<Location> {
+    fn from(board: Board) -> Self {
+        board.tiles
     }
 }
 
 impl Debug for Board {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // ? TODO: find a smaller way to write this to avoid repetition
         let top = self
             .tiles
@@ -127,9 +232,9 @@ impl Debug for Board {
                     Some(tile) => format!("{tile:?}"),
                     None => String::from("   "),
                 };
-                str.push_str(&format!("{tile_str}"));
+                str.push_str(&tile_str);
             }
-            str.push_str("\n");
+            str.push('\n');
         }
 
         write!(f, "{str}")
@@ -1,13 +1,26 @@
-use std::fmt::{Debug, Formatter, Result};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::notation::{format_tiles, parse_tiles, NotationError};
 
 use crate::player::{Combination, Points};
 
 use super::direction::Direction;
+use super::error::BoardError;
+use super::location::Location;
+use super::placement::Placement;
 use super::position::Position;
+use super::Board;
 
 pub type Moves = Vec<Move>;
 
-#[derive(Clone)]
+/// Tiles from `combination` placed one after the other,
+/// starting at `position` and going to `direction`.
+///
+/// Tiles already on the board are stepped over,
+/// so a combination can fill gaps or extend a line on both sides.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PartialMove {
     pub combination: Combination,
     pub position: Position,
@@ -15,12 +28,36 @@ pub struct PartialMove {
 }
 
 impl PartialMove {
-    pub fn into_move(&self, points: Points) -> Move {
+    /// Returns where each tile of the combination lands on `board`.
+    /// The first tile is always at `position`, next ones on the following free positions.
+    /// Fails if a position can't be represented.
+    pub fn placement(&self, board: &Board) -> Result<Placement, BoardError> {
+        let PartialMove {
+            combination,
+            position,
+            direction,
+        } = self;
+
+        let mut locations = Vec::new();
+        let mut position = *position;
+        for (index, &tile) in combination.iter().enumerate() {
+            if index > 0 {
+                position = board
+                    .next_free_position(position, *direction)
+                    .ok_or(BoardError::OutOfRange(position))?;
+            }
+            locations.push(Location { position, tile });
+        }
+
+        Ok(Placement { locations })
+    }
+
+    pub fn into_move(self, points: Points) -> Move {
         let PartialMove {
             combination,
             position,
             direction,
-        } = self.clone();
+        } = self;
 
         Move {
             combination,
@@ -31,8 +68,41 @@ impl PartialMove {
     }
 }
 
+impl Display for PartialMove {
+    /// Writes the move in notation, e.g. `bObS@3,-2>`, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let PartialMove {
+            combination,
+            position,
+            direction,
+        } = self;
+        write!(f, "{}@{position}{direction}", format_tiles(combination))
+    }
+}
+
+impl FromStr for PartialMove {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = || NotationError::Move(text.to_owned());
+        let (combination, rest) = text.split_once('@').ok_or_else(error)?;
+        let direction_index = rest
+            .char_indices()
+            .last()
+            .map(|(index, _)| index)
+            .ok_or_else(error)?;
+        let (position, direction) = rest.split_at(direction_index);
+
+        Ok(PartialMove {
+            combination: parse_tiles(combination)?,
+            position: position.parse()?,
+            direction: direction.parse()?,
+        })
+    }
+}
+
 impl Debug for PartialMove {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let PartialMove {
             combination,
             position,
@@ -43,6 +113,7 @@ impl Debug for PartialMove {
 }
 
 #[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Move {
     pub combination: Combination,
     pub position: Position,
@@ -51,13 +122,13 @@ pub struct Move {
 }
 
 impl Move {
-    pub fn into_partial(&self) -> (PartialMove, Points) {
+    pub fn into_partial(self) -> (PartialMove, Points) {
         let Move {
             combination,
             position,
             direction,
             points,
-        } = self.clone();
+        } = self;
 
         (
             PartialMove {
@@ -71,7 +142,7 @@ impl Move {
 }
 
 impl Debug for Move {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let Move {
             combination,
             position,
@@ -82,9 +153,29 @@ impl Debug for Move {
     }
 }
 
+impl Display for Move {
+    /// Writes the move in notation with its points, e.g. `bObS@3,-2>+4`, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (partial_move, points) = self.clone().into_partial();
+        write!(f, "{partial_move}+{points}")
+    }
+}
+
+impl FromStr for Move {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = || NotationError::Move(text.to_owned());
+        let (partial_move, points) = text.rsplit_once('+').ok_or_else(error)?;
+        let points = points.parse::<Points>().map_err(|_| error())?;
+
+        Ok(partial_move.parse::<PartialMove>()?.into_move(points))
+    }
+}
+
 impl PartialOrd for Move {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.points.cmp(&other.points))
+        Some(self.cmp(other))
     }
 }
 
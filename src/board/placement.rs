@@ -0,0 +1,82 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use crate::tile::Tiles;
+
+use super::direction::Direction;
+use super::location::Location;
+use super::position::Position;
+use super::r#move::PartialMove;
+use super::Board;
+
+/// Tiles to place on the board, each one at its own position.
+///
+/// To be valid, every location must be in the same row or column,
+/// but tiles don't need to be next to each other:
+/// they can be dropped on both sides of, or between, tiles already on the board.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Placement {
+    pub locations: Vec<Location>,
+}
+
+impl Placement {
+    /// Returns positions of every location.
+    pub fn positions(&self) -> Vec<Position> {
+        self.locations
+            .iter()
+            .map(|location| location.position)
+            .collect()
+    }
+
+    /// Returns tiles of every location.
+    pub fn tiles(&self) -> Tiles {
+        self.locations
+            .iter()
+            .map(|location| location.tile)
+            .collect()
+    }
+
+    /// Returns the direction of the line holding every location,
+    /// east for a row and north for a column, or `None` if locations aren't aligned.
+    /// A single tile is considered to be in a row.
+    pub fn direction(&self) -> Option<Direction> {
+        let first = self.locations.first()?.position;
+
+        if self.locations.iter().all(|l| l.position.y == first.y) {
+            Some(Direction(1, 0))
+        } else if self.locations.iter().all(|l| l.position.x == first.x) {
+            Some(Direction(0, 1))
+        } else {
+            None
+        }
+    }
+
+    /// Converts an aligned placement into a `PartialMove` on `board`,
+    /// starting from the southern or western tile.
+    /// Returns `None` if locations aren't aligned, or if a free position of `board`
+    /// lies between two of them, as a `PartialMove` can't step over it.
+    pub fn into_partial_move(mut self, board: &Board) -> Option<PartialMove> {
+        let direction = self.direction()?;
+        self.locations
+            .sort_by_key(|location| (location.position.x, location.position.y));
+
+        let is_gapless = self.locations.windows(2).all(|pair| {
+            board.next_free_position(pair[0].position, direction) == Some(pair[1].position)
+        });
+        if !is_gapless {
+            return None;
+        }
+
+        Some(PartialMove {
+            combination: self.tiles(),
+            position: self.locations[0].position,
+            direction,
+        })
+    }
+}
+
+impl Debug for Placement {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:?}", self.locations)
+    }
+}
@@ -1,15 +1,71 @@
-use std::fmt::{Debug, Formatter, Result};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+use crate::notation::NotationError;
+
+use super::direction::Direction;
+
+/// Type of `x` and `y` coordinates on the board.
+pub type Coordinate = i16;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Test of a documentation
 pub struct Position {
-    pub x: i8,
-    pub y: i8,
+    pub x: Coordinate,
+    pub y: Coordinate,
+}
+
+impl Position {
+    /// Lowest coordinate a tile can be placed at.
+    /// One position is kept around the board, so neighbors of any tile can be reached.
+    pub const MIN: Coordinate = Coordinate::MIN + 1;
+    /// Highest coordinate a tile can be placed at, see `Position::MIN`.
+    pub const MAX: Coordinate = Coordinate::MAX - 1;
+
+    /// Returns `true` if a tile can be placed at this position.
+    pub fn is_in_range(&self) -> bool {
+        let range = Position::MIN..=Position::MAX;
+        range.contains(&self.x) && range.contains(&self.y)
+    }
+
+    /// Returns the position `n` steps away in `direction`,
+    /// or `None` if it can't be represented.
+    pub fn step(&self, direction: Direction, n: Coordinate) -> Option<Position> {
+        let Direction(dx, dy) = direction;
+
+        Some(Position {
+            x: self.x.checked_add((dx as Coordinate).checked_mul(n)?)?,
+            y: self.y.checked_add((dy as Coordinate).checked_mul(n)?)?,
+        })
+    }
 }
 
 impl Debug for Position {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let Position { x, y } = self;
         write!(f, "({x}, {y})")
     }
 }
+
+impl Display for Position {
+    /// Writes the position in notation, e.g. `3,-2`, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Position { x, y } = self;
+        write!(f, "{x},{y}")
+    }
+}
+
+impl FromStr for Position {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = || NotationError::Position(text.to_owned());
+        let (x, y) = text.split_once(',').ok_or_else(error)?;
+
+        Ok(Position {
+            x: x.parse().map_err(|_| error())?,
+            y: y.parse().map_err(|_| error())?,
+        })
+    }
+}
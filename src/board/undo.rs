@@ -0,0 +1,14 @@
+use std::fmt::{Debug, Formatter, Result};
+
+/// Returned by `Board::apply()`, to take placed tiles back with `Board::undo()`.
+#[must_use]
+pub struct UndoToken {
+    /// How many tiles were on the board before placing new ones.
+    pub(super) tiles_number: usize,
+}
+
+impl Debug for UndoToken {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "UndoToken({})", self.tiles_number)
+    }
+}
@@ -0,0 +1,564 @@
+pub mod outcome;
+pub mod record;
+pub mod snapshot;
+pub mod turn;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::bag::{Bag, BagToken};
+use crate::board::{undo::UndoToken, Board};
+use crate::player::{action::Action, moves::is_playable, Player, Points};
+use crate::rules::{error::MoveError, ruleset::RuleSet};
+use crate::strategy::{greedy::GreedyStrategy, Strategy, View};
+use crate::tile::Tiles;
+use crate::tile_set::TileSet;
+use outcome::{EndReason, GameOutcome};
+use record::GameRecord;
+use snapshot::{PlayerSnapshot, Snapshot};
+use turn::TurnResult;
+
+/// A game of Qwirkle: owns the bag, the board, the players and the turn order.
+pub struct Game {
+    rules: RuleSet,
+    /// Every tile of the game, wherever it is, see `debug_assert_conservation()`.
+    full_set: TileSet,
+    bag: Bag,
+    board: Board,
+    players: Vec<Player>,
+    /// Index of the player who plays next.
+    current: usize,
+    /// How many times players have played since the beginning of the game.
+    steps: u32,
+    outcome: Option<GameOutcome>,
+    /// Every action played since the beginning of the game,
+    /// or `None` if the game was resumed from a snapshot.
+    record: Option<GameRecord>,
+    /// Seed of `draw_rng` and `rng`, which fully reproduces the game.
+    seed: u64,
+    /// Source of tiles drawn from the bag.
+    /// It is kept apart from `rng`, so replaying actions draws the same tiles
+    /// whatever strategies did to choose them.
+    draw_rng: StdRng,
+    /// Source of strategies' random decisions.
+    rng: StdRng,
+    /// State before every action played, the last action being the first to undo.
+    history: Vec<Undo>,
+    /// Actions taken back by `undo()`, the last one being the first to redo.
+    undone: Vec<Action>,
+}
+
+/// What an action changed, to put the game back as it was before it.
+struct Undo {
+    result: TurnResult,
+    board: UndoToken,
+    bag: BagToken,
+    hand: Tiles,
+    score: Points,
+    current: usize,
+    outcome: Option<GameOutcome>,
+    /// Restored so that redoing the action draws the same tiles,
+    /// and playing another one draws tiles as a replay of the record would.
+    draw_rng: StdRng,
+}
+
+impl Game {
+    /// Constructs a new game with `players_number` players,
+    /// each one drawing 6 tiles from a new bag.
+    /// The game is seeded randomly, see `seed()` to reproduce it.
+    ///
+    /// The player holding the longest combination of tiles starts,
+    /// see `opening_player()`.
+    ///
+    /// Panics if `players_number` is 0.
+    pub fn new(players_number: u8) -> Game {
+        Game::with_seed(players_number, rand::random())
+    }
+
+    /// Same as `new()`, but every random decision is drawn from `seed`:
+    /// two games with the same seed are identical.
+    pub fn with_seed(players_number: u8, seed: u64) -> Game {
+        let strategies = (0..players_number)
+            .map(|_| Box::new(GreedyStrategy) as Box<dyn Strategy>)
+            .collect();
+
+        Game::with_strategies(strategies, seed)
+    }
+
+    /// Constructs a new game with one player per strategy, in turn order.
+    /// Each player draws 6 tiles from a new bag.
+    /// Every random decision, including strategies' ones, is drawn from `seed`.
+    ///
+    /// Panics if there isn't any strategy.
+    pub fn with_strategies(strategies: Vec<Box<dyn Strategy>>, seed: u64) -> Game {
+        Game::with_rules(RuleSet::default(), strategies, seed)
+    }
+
+    /// Same as `with_strategies()`, but the game is played with `rules`,
+    /// e.g. a smaller variant.
+    ///
+    /// Panics if there isn't any strategy, or if `rules` aren't valid, see `RuleSet::validate()`.
+    pub fn with_rules(rules: RuleSet, strategies: Vec<Box<dyn Strategy>>, seed: u64) -> Game {
+        assert!(!strategies.is_empty(), "a game needs at least one player");
+        if let Err(error) = rules.validate() {
+            panic!("invalid rules: {error}");
+        }
+
+        let (mut draw_rng, rng) = seeded_rngs(seed);
+        let mut bag = Bag::with_rules(&rules);
+        let players = strategies
+            .into_iter()
+            .enumerate()
+            .map(|(index, strategy)| {
+                Player::with_strategy(index as u8 + 1, &rules, &mut bag, strategy, &mut draw_rng)
+            })
+            .collect::<Vec<Player>>();
+
+        let record = GameRecord {
+            rules,
+            seed,
+            hands: players
+                .iter()
+                .map(|player| (player.id, player.hand.clone()))
+                .collect(),
+            turns: Vec::new(),
+        };
+
+        Game {
+            full_set: rules.tile_set(),
+            rules,
+            bag,
+            board: Board::new(),
+            current: opening_player(&players),
+            players,
+            steps: 0,
+            outcome: None,
+            record: Some(record),
+            seed,
+            draw_rng,
+            rng,
+            history: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// Returns the seed the game was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Resumes a game from a `snapshot`, played with `rules`
+    /// and one strategy per player of the snapshot, in turn order.
+    /// Random decisions from now on are drawn from `seed`, and turns are counted from 1 again.
+    ///
+    /// Panics if the snapshot has no player, if there isn't exactly one strategy per player,
+    /// or if `rules` aren't valid, see `RuleSet::validate()`.
+    pub fn from_snapshot(
+        snapshot: Snapshot,
+        rules: RuleSet,
+        strategies: Vec<Box<dyn Strategy>>,
+        seed: u64,
+    ) -> Game {
+        if let Err(error) = rules.validate() {
+            panic!("invalid rules: {error}");
+        }
+        assert!(
+            !snapshot.players.is_empty(),
+            "a game needs at least one player"
+        );
+        assert_eq!(
+            snapshot.players.len(),
+            strategies.len(),
+            "every player of the snapshot needs a strategy"
+        );
+
+        let players = snapshot
+            .players
+            .into_iter()
+            .zip(strategies)
+            .map(|(PlayerSnapshot { id, points, hand }, strategy)| {
+                let mut player = Player {
+                    id,
+                    points,
+                    hand,
+                    combinations: Vec::new(),
+                    strategy,
+                };
+                player.update_combinations();
+
+                player
+            })
+            .collect::<Vec<Player>>();
+
+        let (draw_rng, rng) = seeded_rngs(seed);
+        let mut game = Game {
+            rules,
+            full_set: TileSet::new(),
+            bag: Bag::from_tiles(snapshot.bag),
+            board: snapshot.board,
+            players,
+            current: snapshot.current,
+            steps: 0,
+            outcome: None,
+            record: None,
+            seed,
+            draw_rng,
+            rng,
+            history: Vec::new(),
+            undone: Vec::new(),
+        };
+        game.full_set = game.tile_set();
+
+        game
+    }
+
+    /// Returns the current position of the game, to save it or resume it later.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            board: self.board.clone(),
+            players: self
+                .players
+                .iter()
+                .map(|player| PlayerSnapshot {
+                    id: player.id,
+                    points: player.points,
+                    hand: player.hand.clone(),
+                })
+                .collect(),
+            bag: self.bag.tiles().clone(),
+            current: self.current,
+        }
+    }
+
+    /// Returns every action played since the beginning of the game, see `record::replay()`.
+    /// Returns `None` if the game was resumed from a snapshot, as it can't be replayed.
+    pub fn record(&self) -> Option<&GameRecord> {
+        self.record.as_ref()
+    }
+
+    /// Returns rules the game is played with.
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    /// Returns the bag to draw from.
+    pub fn bag(&self) -> &Bag {
+        &self.bag
+    }
+
+    /// Returns the board played on.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns all players, in turn order.
+    pub fn players(&self) -> &Vec<Player> {
+        &self.players
+    }
+
+    /// Returns the player who plays next.
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.current]
+    }
+
+    /// Returns the current turn number, starting at 1.
+    pub fn turn(&self) -> u32 {
+        self.steps / self.players.len() as u32 + 1
+    }
+
+    /// Returns `true` once the game has ended, see `outcome()`.
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Returns how the game ended, or `None` while it is still running.
+    pub fn outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Returns the score of every player id, in turn order.
+    pub fn scores(&self) -> Vec<(u8, Points)> {
+        self.players
+            .iter()
+            .map(|player| (player.id, player.points))
+            .collect()
+    }
+
+    /// Returns how many tiles every player holds, in turn order.
+    pub fn hand_sizes(&self) -> Vec<usize> {
+        self.players
+            .iter()
+            .map(|player| player.hand.len())
+            .collect()
+    }
+
+    /// Builds what player `player_id` knows, as shown to its strategy, and passes it to `f`.
+    /// Returns `None` if there is no such player.
+    pub fn with_view<T>(&self, player_id: u8, f: impl FnOnce(&View) -> T) -> Option<T> {
+        let player = self.players.iter().find(|player| player.id == player_id)?;
+        let scores = self.scores();
+        let hand_sizes = self.hand_sizes();
+
+        Some(f(&View {
+            player_id,
+            rules: &self.rules,
+            board: &self.board,
+            hand: &player.hand,
+            bag_size: self.bag.len(),
+            scores: &scores,
+            hand_sizes: &hand_sizes,
+        }))
+    }
+
+    /// Lets the current player's strategy choose an action and plays it,
+    /// then passes the hand to the next player.
+    /// Returns `None` if the game is already over.
+    ///
+    /// If the strategy chooses an illegal action, the player passes,
+    /// and the refused action is returned in `TurnResult::refused`.
+    pub fn step(&mut self) -> Option<TurnResult> {
+        if self.is_over() {
+            return None;
+        }
+
+        let scores = self.scores();
+        let hand_sizes = self.hand_sizes();
+        let bag_size = self.bag.len();
+        let player = &mut self.players[self.current];
+        let action = player.choose_action(
+            &self.rules,
+            &self.board,
+            bag_size,
+            &scores,
+            &hand_sizes,
+            &mut self.rng,
+        );
+
+        match self.play_action(action.clone()) {
+            Ok(result) => Some(result),
+            Err(error) => {
+                let mut result = self.play_action(Action::Pass).ok()?;
+                result.refused = Some((action, error));
+
+                Some(result)
+            }
+        }
+    }
+
+    /// Plays `action` for the current player, e.g. an action from a human player,
+    /// then passes the hand to the next player.
+    /// If `action` is illegal, nothing changes and the reason is returned.
+    ///
+    /// The game ends when the bag is empty and the player empties its hand,
+    /// or when nobody can play anymore (see `is_blocked()`).
+    ///
+    /// Actions taken back by `undo()` can't be redone anymore.
+    pub fn play_action(&mut self, action: Action) -> Result<TurnResult, MoveError> {
+        let result = self.apply(action)?;
+        self.undone.clear();
+
+        Ok(result)
+    }
+
+    /// Takes back the last action played, putting the board, the bag,
+    /// the player's hand and score as they were before it.
+    /// Returns the undone action's result, or `None` if nothing was played yet.
+    pub fn undo(&mut self) -> Option<TurnResult> {
+        let undo = self.history.pop()?;
+
+        self.board.undo(undo.board);
+        self.bag.undo(undo.bag);
+        self.current = undo.current;
+        self.steps -= 1;
+        self.outcome = undo.outcome;
+        self.draw_rng = undo.draw_rng;
+
+        let player = &mut self.players[self.current];
+        player.hand = undo.hand;
+        player.points = undo.score;
+        player.update_combinations();
+
+        if let Some(record) = self.record.as_mut() {
+            record.turns.pop();
+        }
+
+        self.undone.push(undo.result.action.clone());
+
+        Some(undo.result)
+    }
+
+    /// Plays again the last action taken back by `undo()`, drawing the same tiles.
+    /// Returns its result, or `None` if there is nothing to redo.
+    pub fn redo(&mut self) -> Option<TurnResult> {
+        let action = self.undone.pop()?;
+
+        self.apply(action).ok()
+    }
+
+    /// Plays `action`, see `play_action()`, and saves what it changes to undo it.
+    fn apply(&mut self, action: Action) -> Result<TurnResult, MoveError> {
+        if self.is_over() {
+            return Err(MoveError::GameOver);
+        }
+
+        let turn = self.turn();
+        let board = self.board.checkpoint();
+        let bag = self.bag.checkpoint();
+        let draw_rng = self.draw_rng.clone();
+        let current = self.current;
+        let outcome = self.outcome.clone();
+
+        let player = &mut self.players[self.current];
+        let hand = player.hand.clone();
+        let score = player.points;
+        let hand_size = player.hand.len();
+
+        let points = player.apply(
+            &action,
+            &self.rules,
+            &mut self.board,
+            &mut self.bag,
+            &mut self.draw_rng,
+        )?;
+
+        // new tiles are always appended to the hand, after the ones kept
+        let played = match &action {
+            Action::Place(r#move) => r#move.combination.len(),
+            Action::Exchange(tiles) => tiles.len(),
+            Action::Pass => 0,
+        };
+        let drawn = player.hand[hand_size - played..].to_vec();
+
+        let result = TurnResult {
+            turn,
+            player_id: player.id,
+            action,
+            drawn,
+            points,
+            score: player.points,
+            refused: None,
+        };
+
+        Ok(self.end_turn(Undo {
+            result,
+            board,
+            bag,
+            hand,
+            score,
+            current,
+            outcome,
+            draw_rng,
+        }))
+    }
+
+    /// Records the action of `undo`, just played by the current player,
+    /// passes the hand to the next player and ends the game if needed.
+    fn end_turn(&mut self, undo: Undo) -> TurnResult {
+        let result = undo.result.clone();
+        if let Some(record) = self.record.as_mut() {
+            record.turns.push(result.clone());
+        }
+
+        let has_emptied_hand = self.players[self.current].hand.is_empty() && self.bag.is_empty();
+
+        self.current = (self.current + 1) % self.players.len();
+        self.steps += 1;
+
+        if has_emptied_hand {
+            self.end(EndReason::HandEmptied(result.player_id));
+        } else if !matches!(result.action, Action::Place(_)) && self.is_blocked() {
+            // a blocked game can only be noticed when someone fails to place tiles
+            self.end(EndReason::Blocked);
+        }
+
+        self.history.push(undo);
+        self.debug_assert_conservation();
+
+        result
+    }
+
+    /// Returns `true` if no player can place a tile
+    /// and no tile from the bag could be placed either,
+    /// so exchanging tiles can't unlock the game.
+    pub fn is_blocked(&self) -> bool {
+        let can_place = self
+            .players
+            .iter()
+            .any(|player| player.can_play(&self.rules, &self.board));
+        let can_draw_playable = self
+            .bag
+            .tiles()
+            .iter()
+            .any(|&tile| is_playable(&self.rules, &self.board, tile));
+
+        !can_place && !can_draw_playable
+    }
+
+    /// Returns every tile of the game: tiles in the bag, in players' hands and on the board.
+    pub fn tile_set(&self) -> TileSet {
+        let mut set = self.bag.tiles().iter().copied().collect::<TileSet>();
+        for player in &self.players {
+            set.extend(player.hand.iter().copied());
+        }
+        set.extend(self.board.tiles().iter().map(|location| location.tile));
+
+        set
+    }
+
+    /// Checks, in debug builds only, that no tile has been lost or duplicated,
+    /// i.e. that `tile_set()` is still the full set of `rules`,
+    /// or tiles of the snapshot the game was resumed from. Run after every action.
+    pub fn debug_assert_conservation(&self) {
+        debug_assert_eq!(self.tile_set(), self.full_set, "tiles aren't conserved");
+    }
+
+    /// Ends the game for a given `reason`, freezing scores.
+    fn end(&mut self, reason: EndReason) {
+        let scores = self.scores();
+
+        self.outcome = Some(GameOutcome { reason, scores });
+    }
+
+    /// Plays a full turn, i.e. lets every player play once.
+    /// Stops early if the game ends during the turn.
+    pub fn play_turn(&mut self) -> Vec<TurnResult> {
+        let turn = self.turn();
+        let mut results = Vec::new();
+
+        while !self.is_over() && self.turn() == turn {
+            if let Some(result) = self.step() {
+                results.push(result);
+            }
+        }
+
+        results
+    }
+
+    /// Returns the player with the highest score once the game is over.
+    /// On a tie, the first player in turn order wins.
+    pub fn winner(&self) -> Option<&Player> {
+        let id = self.outcome.as_ref()?.winner()?;
+
+        self.players.iter().find(|player| player.id == id)
+    }
+}
+
+/// Returns the index of the player who opens the game,
+/// i.e. the one whose hand holds the longest combination.
+/// On a tie, the first player in turn order starts.
+pub fn opening_player(players: &[Player]) -> usize {
+    players
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, player)| player.best_opening().map_or(0, |opening| opening.len()))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Returns the source of tiles drawn from the bag and the source of strategies' decisions,
+/// both derived from `seed`.
+fn seeded_rngs(seed: u64) -> (StdRng, StdRng) {
+    let mut draw_rng = StdRng::seed_from_u64(seed);
+    let rng = StdRng::from_rng(&mut draw_rng);
+
+    (draw_rng, rng)
+}
@@ -0,0 +1,51 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use crate::player::Points;
+
+/// Why a game ended.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum EndReason {
+    /// The bag is empty and the player with this id played its last tile.
+    HandEmptied(u8),
+    /// No player can place a tile and the bag can't provide any playable tile.
+    Blocked,
+}
+
+impl Debug for EndReason {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            EndReason::HandEmptied(id) => write!(f, "Player {id} emptied its hand"),
+            EndReason::Blocked => write!(f, "No player can play"),
+        }
+    }
+}
+
+/// Final state of a game: why it ended and final scores of every player.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameOutcome {
+    pub reason: EndReason,
+    /// Final score of each player id, in turn order.
+    pub scores: Vec<(u8, Points)>,
+}
+
+impl GameOutcome {
+    /// Returns the id of the player with the highest score.
+    /// On a tie, the first player in turn order wins.
+    pub fn winner(&self) -> Option<u8> {
+        self.scores
+            .iter()
+            .rev()
+            .max_by_key(|&&(_, points)| points)
+            .map(|&(id, _)| id)
+    }
+}
+
+impl Debug for GameOutcome {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let GameOutcome { reason, scores } = self;
+        write!(f, "{reason:?}, scores: {scores:?}")
+    }
+}
@@ -0,0 +1,322 @@
+//! Full record of a game, to archive it and replay it up to any moment.
+//!
+//! A record is written one line per entry, every tile and action being written in `notation`:
+//!
+//! ```text
+//! rules 6 6 3 6 6 6 6
+//! seed 42
+//! hand 1 rSbOgOyDp+o*
+//! hand 2 bCbDg+yOrCpS
+//! turn 1 player 2 bCbD@0,0>+2 draw rO points 2 score 2
+//! turn 1 player 1 exchange yDp+ draw bSgC points 0 score 0
+//! turn 2 player 2 pass draw points 0 score 2
+//! ```
+//!
+//! - `rules`: colors, shapes, copies, hand size, Qwirkle length, Qwirkle bonus and end bonus,
+//!   see `RuleSet`,
+//! - `seed`: seed of the game, which determines every tile drawn from the bag,
+//! - `hand`: id and hand of a player after the initial draw, one line per player in turn order,
+//! - `turn`: one line per action, in the order they were played, see `TurnResult`.
+//!
+//! `rules`, `seed` and at least one `hand` are required.
+//! Empty lines and lines starting with `#` are ignored.
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::notation::{format_tiles, parse_tiles, NotationError};
+use crate::player::Points;
+use crate::rules::{
+    error::{MoveError, RuleSetError},
+    ruleset::RuleSet,
+};
+use crate::strategy::{greedy::GreedyStrategy, Strategy};
+use crate::tile::Tiles;
+
+use super::turn::TurnResult;
+use super::Game;
+
+/// Everything needed to replay a game: rules, seed, initial hands and every action.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    pub rules: RuleSet,
+    pub seed: u64,
+    /// Id and hand of every player after the initial draw, in turn order.
+    pub hands: Vec<(u8, Tiles)>,
+    /// Every action played, with tiles drawn and points earned.
+    pub turns: Vec<TurnResult>,
+}
+
+impl Debug for GameRecord {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serialize(self))
+    }
+}
+
+/// Why a record can't be parsed. Lines are numbered from 1.
+#[derive(Clone, PartialEq, Eq)]
+pub enum RecordError {
+    /// This line has an unknown keyword, a missing or extra field, or a repeated entry.
+    Syntax(usize),
+    /// Tiles or actions on this line aren't valid notation.
+    Notation(usize, NotationError),
+    /// Rules on this line can't be played.
+    Rules(usize, RuleSetError),
+    /// No line starts with this required keyword.
+    Missing(&'static str),
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RecordError::Syntax(line) => write!(f, "invalid line {line}"),
+            RecordError::Notation(line, error) => write!(f, "{error} on line {line}"),
+            RecordError::Rules(line, error) => write!(f, "invalid rules on line {line}: {error}"),
+            RecordError::Missing(keyword) => write!(f, "missing {keyword} line"),
+        }
+    }
+}
+
+impl Debug for RecordError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "RecordError({self})")
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+/// Why a record can't be replayed. Turns are indexed from 0, in `GameRecord::turns`.
+#[derive(Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// Recorded rules can't be played.
+    Rules(RuleSetError),
+    /// The record has no player.
+    NoPlayers,
+    /// Hands drawn with the recorded seed differ from recorded hands.
+    Hands,
+    /// The recorded turn is played by another player than the one expected.
+    WrongPlayer(usize),
+    /// The recorded action is illegal.
+    Illegal(usize, MoveError),
+    /// Tiles drawn, points or score differ from the recorded turn.
+    Mismatch(usize),
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Rules(error) => write!(f, "invalid rules: {error}"),
+            ReplayError::NoPlayers => write!(f, "no player"),
+            ReplayError::Hands => write!(f, "initial hands differ"),
+            ReplayError::WrongPlayer(index) => write!(f, "turn {index} played by wrong player"),
+            ReplayError::Illegal(index, error) => write!(f, "turn {index} is illegal: {error}"),
+            ReplayError::Mismatch(index) => write!(f, "turn {index} differs from record"),
+        }
+    }
+}
+
+impl Debug for ReplayError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "ReplayError({self})")
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Replays every turn of `record`, see `replay_to()`.
+pub fn replay(record: &GameRecord) -> Result<Game, ReplayError> {
+    replay_to(record, record.turns.len())
+}
+
+/// Replays the first `steps` turns of `record` on a new game,
+/// checking that every turn gives the same draws and scores as recorded.
+/// Returns the game as it was after these turns, played by `GreedyStrategy` from then on.
+pub fn replay_to(record: &GameRecord, steps: usize) -> Result<Game, ReplayError> {
+    record.rules.validate().map_err(ReplayError::Rules)?;
+    if record.hands.is_empty() {
+        return Err(ReplayError::NoPlayers);
+    }
+
+    let strategies = (0..record.hands.len())
+        .map(|_| Box::new(GreedyStrategy) as Box<dyn Strategy>)
+        .collect();
+    let mut game = Game::with_rules(record.rules, strategies, record.seed);
+
+    let hands = game
+        .players()
+        .iter()
+        .map(|player| (player.id, player.hand.clone()))
+        .collect::<Vec<_>>();
+    if hands != record.hands {
+        return Err(ReplayError::Hands);
+    }
+
+    for (index, recorded) in record.turns.iter().take(steps).enumerate() {
+        if game.current_player().id != recorded.player_id {
+            return Err(ReplayError::WrongPlayer(index));
+        }
+
+        let result = game
+            .play_action(recorded.action.clone())
+            .map_err(|error| ReplayError::Illegal(index, error))?;
+        if result != *recorded {
+            return Err(ReplayError::Mismatch(index));
+        }
+    }
+
+    Ok(game)
+}
+
+/// Writes `record` in the record format, see `record`.
+/// Parsing the returned text gives back the same record.
+pub fn serialize(record: &GameRecord) -> String {
+    let RuleSet {
+        colors_number,
+        shapes_number,
+        copies,
+        hand_size,
+        qwirkle_length,
+        qwirkle_bonus,
+        end_bonus,
+    } = record.rules;
+
+    let mut lines = vec![
+        format!("rules {colors_number} {shapes_number} {copies} {hand_size} {qwirkle_length} {qwirkle_bonus} {end_bonus}"),
+        format!("seed {}", record.seed),
+    ];
+
+    for (id, hand) in record.hands.iter() {
+        lines.push(format!("hand {id} {}", format_tiles(hand)));
+    }
+
+    for TurnResult {
+        turn,
+        player_id,
+        action,
+        drawn,
+        points,
+        score,
+        ..
+    } in record.turns.iter()
+    {
+        lines.push(format!(
+            "turn {turn} player {player_id} {action} draw {} points {points} score {score}",
+            format_tiles(drawn)
+        ));
+    }
+
+    lines
+        .into_iter()
+        .map(|line| line.trim_end().to_owned() + "\n")
+        .collect()
+}
+
+/// Reads a record written in the record format, see `record`.
+pub fn parse(text: &str) -> Result<GameRecord, RecordError> {
+    let mut rules = None;
+    let mut seed = None;
+    let mut hands = Vec::new();
+    let mut turns = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let syntax = || RecordError::Syntax(number);
+        let notation = |error| RecordError::Notation(number, error);
+
+        let mut fields = line.split_whitespace();
+        let Some(keyword) = fields.next() else {
+            continue;
+        };
+        let fields = fields.collect::<Vec<&str>>();
+
+        match keyword {
+            _ if keyword.starts_with('#') => continue,
+            "rules" if rules.is_none() => {
+                let [colors_number, shapes_number, copies, hand_size, qwirkle_length, qwirkle_bonus, end_bonus] =
+                    fields[..]
+                else {
+                    return Err(syntax());
+                };
+                let count = |field: &str| field.parse::<usize>().map_err(|_| syntax());
+                let bonus = |field: &str| field.parse::<Points>().map_err(|_| syntax());
+
+                let parsed = RuleSet {
+                    colors_number: count(colors_number)?,
+                    shapes_number: count(shapes_number)?,
+                    copies: count(copies)?,
+                    hand_size: count(hand_size)?,
+                    qwirkle_length: count(qwirkle_length)?,
+                    qwirkle_bonus: bonus(qwirkle_bonus)?,
+                    end_bonus: bonus(end_bonus)?,
+                };
+                parsed
+                    .validate()
+                    .map_err(|error| RecordError::Rules(number, error))?;
+                rules = Some(parsed);
+            }
+            "seed" if seed.is_none() => {
+                let [value] = fields[..] else {
+                    return Err(syntax());
+                };
+                seed = Some(value.parse().map_err(|_| syntax())?);
+            }
+            "hand" => {
+                let (id, hand) = match fields[..] {
+                    [id] => (id, ""),
+                    [id, hand] => (id, hand),
+                    _ => return Err(syntax()),
+                };
+                hands.push((
+                    id.parse().map_err(|_| syntax())?,
+                    parse_tiles(hand).map_err(notation)?,
+                ));
+            }
+            "turn" => turns.push(parse_turn(number, &fields)?),
+            _ => return Err(syntax()),
+        }
+    }
+
+    let rules = rules.ok_or(RecordError::Missing("rules"))?;
+    let seed = seed.ok_or(RecordError::Missing("seed"))?;
+    if hands.is_empty() {
+        return Err(RecordError::Missing("hand"));
+    }
+
+    Ok(GameRecord {
+        rules,
+        seed,
+        hands,
+        turns,
+    })
+}
+
+/// Parses fields of a `turn` line, following the `turn` keyword, at line `number`.
+fn parse_turn(number: usize, fields: &[&str]) -> Result<TurnResult, RecordError> {
+    let syntax = || RecordError::Syntax(number);
+    let notation = |error| RecordError::Notation(number, error);
+
+    let draw = fields
+        .iter()
+        .position(|&field| field == "draw")
+        .ok_or_else(syntax)?;
+    let (head, tail) = fields.split_at(draw);
+
+    let [turn, "player", player_id, ref action @ ..] = head[..] else {
+        return Err(syntax());
+    };
+    let (drawn, points, score) = match tail[..] {
+        ["draw", "points", points, "score", score] => ("", points, score),
+        ["draw", drawn, "points", points, "score", score] => (drawn, points, score),
+        _ => return Err(syntax()),
+    };
+
+    Ok(TurnResult {
+        turn: turn.parse().map_err(|_| syntax())?,
+        player_id: player_id.parse().map_err(|_| syntax())?,
+        action: action.join(" ").parse().map_err(notation)?,
+        drawn: parse_tiles(drawn).map_err(notation)?,
+        points: points.parse().map_err(|_| syntax())?,
+        score: score.parse().map_err(|_| syntax())?,
+        refused: None,
+    })
+}
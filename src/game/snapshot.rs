@@ -0,0 +1,203 @@
+//! Text format to save and load a position, e.g. in files, bug reports or tests.
+//!
+//! A snapshot has one section per line, starting with a keyword,
+//! every tile being written in `notation`:
+//!
+//! ```text
+//! board yS@0,0 yC@1,0
+//! player 1 12 bObS
+//! player 2 7 rSrO
+//! bag oDp+g*
+//! next 2
+//! ```
+//!
+//! - `board`: tiles on the board, in the order they were placed,
+//! - `player`: id, points and hand of a player, one line per player in turn order,
+//! - `bag`: tiles left in the bag,
+//! - `next`: id of the player who plays next.
+//!
+//! Every section is optional: by default the board, the players and the bag are empty,
+//! and the first player plays next.
+//! Empty lines and lines starting with `#` are ignored.
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::board::{error::BoardError, location::Location, Board};
+use crate::notation::{format_tiles, parse_tiles, NotationError};
+use crate::player::Points;
+use crate::tile::Tiles;
+
+/// A player as saved in a snapshot.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerSnapshot {
+    pub id: u8,
+    pub points: Points,
+    pub hand: Tiles,
+}
+
+impl Debug for PlayerSnapshot {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let PlayerSnapshot { id, points, hand } = self;
+        write!(f, "Player {id} ({points} points): {}", format_tiles(hand))
+    }
+}
+
+/// A position during a game: enough to resume it, see `Game::from_snapshot()`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub board: Board,
+    /// Every player, in turn order.
+    pub players: Vec<PlayerSnapshot>,
+    /// Tiles left in the bag.
+    pub bag: Tiles,
+    /// Index of the player who plays next.
+    pub current: usize,
+}
+
+impl Debug for Snapshot {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serialize(self))
+    }
+}
+
+/// Why a snapshot can't be parsed. Lines are numbered from 1.
+#[derive(Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// This line has an unknown keyword, a missing or extra field, or a repeated section.
+    Syntax(usize),
+    /// Tiles or positions on this line aren't valid notation.
+    Notation(usize, NotationError),
+    /// Tiles of the board can't be placed.
+    Board(BoardError),
+    /// `next` refers to this id, which isn't a player of the snapshot.
+    UnknownPlayer(u8),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            SnapshotError::Syntax(line) => write!(f, "invalid line {line}"),
+            SnapshotError::Notation(line, error) => write!(f, "{error} on line {line}"),
+            SnapshotError::Board(error) => write!(f, "invalid board: {error}"),
+            SnapshotError::UnknownPlayer(id) => write!(f, "no player with id {id}"),
+        }
+    }
+}
+
+impl Debug for SnapshotError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "SnapshotError({self})")
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<BoardError> for SnapshotError {
+    fn from(error: BoardError) -> Self {
+        SnapshotError::Board(error)
+    }
+}
+
+/// Writes `snapshot` in the snapshot format, see `snapshot`.
+/// Parsing the returned text gives back the same snapshot.
+pub fn serialize(snapshot: &Snapshot) -> String {
+    let mut lines = Vec::new();
+
+    let board = snapshot
+        .board
+        .tiles()
+        .iter()
+        .map(|location| format!(" {location}"))
+        .collect::<String>();
+    lines.push(format!("board{board}"));
+
+    for PlayerSnapshot { id, points, hand } in snapshot.players.iter() {
+        lines.push(format!("player {id} {points} {}", format_tiles(hand)));
+    }
+
+    lines.push(format!("bag {}", format_tiles(&snapshot.bag)));
+
+    if let Some(player) = snapshot.players.get(snapshot.current) {
+        lines.push(format!("next {}", player.id));
+    }
+
+    lines
+        .into_iter()
+        .map(|line| line.trim_end().to_owned() + "\n")
+        .collect()
+}
+
+/// Reads a snapshot written in the snapshot format, see `snapshot`.
+pub fn parse(text: &str) -> Result<Snapshot, SnapshotError> {
+    let mut board = None;
+    let mut players = Vec::new();
+    let mut bag = None;
+    let mut next = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let syntax = || SnapshotError::Syntax(number);
+        let notation = |error| SnapshotError::Notation(number, error);
+
+        let mut fields = line.split_whitespace();
+        let Some(keyword) = fields.next() else {
+            continue;
+        };
+        let fields = fields.collect::<Vec<&str>>();
+
+        match keyword {
+            _ if keyword.starts_with('#') => continue,
+            "board" if board.is_none() => {
+                let mut parsed = Board::new();
+                for field in fields {
+                    parsed.add_tile(field.parse::<Location>().map_err(notation)?)?;
+                }
+                board = Some(parsed);
+            }
+            "player" => {
+                let (id, points, hand) = match fields[..] {
+                    [id, points] => (id, points, ""),
+                    [id, points, hand] => (id, points, hand),
+                    _ => return Err(syntax()),
+                };
+                players.push(PlayerSnapshot {
+                    id: id.parse().map_err(|_| syntax())?,
+                    points: points.parse().map_err(|_| syntax())?,
+                    hand: parse_tiles(hand).map_err(notation)?,
+                });
+            }
+            "bag" if bag.is_none() => {
+                let tiles = match fields[..] {
+                    [] => "",
+                    [tiles] => tiles,
+                    _ => return Err(syntax()),
+                };
+                bag = Some(parse_tiles(tiles).map_err(notation)?);
+            }
+            "next" if next.is_none() => {
+                let [id] = fields[..] else {
+                    return Err(syntax());
+                };
+                next = Some(id.parse::<u8>().map_err(|_| syntax())?);
+            }
+            _ => return Err(syntax()),
+        }
+    }
+
+    let current = match next {
+        Some(id) => players
+            .iter()
+            .position(|player| player.id == id)
+            .ok_or(SnapshotError::UnknownPlayer(id))?,
+        None => 0,
+    };
+
+    Ok(Snapshot {
+        board: board.unwrap_or_default(),
+        players,
+        bag: bag.unwrap_or_default(),
+        current,
+    })
+}
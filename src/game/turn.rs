@@ -0,0 +1,49 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use crate::player::{action::Action, Points};
+use crate::rules::error::MoveError;
+use crate::tile::Tiles;
+
+/// Outcome of one player's turn, as emitted by `Game::step()`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TurnResult {
+    /// Turn number, starting at 1. A turn ends once every player has played.
+    pub turn: u32,
+    pub player_id: u8,
+    pub action: Action,
+    /// Tiles drawn from the bag after placing or exchanging tiles.
+    pub drawn: Tiles,
+    /// Points earned during this turn, including end of game bonus.
+    pub points: Points,
+    /// Player's total score after this turn.
+    pub score: Points,
+    /// Illegal action chosen by the player's strategy and why it was refused,
+    /// `action` being the pass played instead.
+    /// Only set in results returned by `Game::step()`: records keep the pass alone.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub refused: Option<(Action, MoveError)>,
+}
+
+impl Debug for TurnResult {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let TurnResult {
+            turn,
+            player_id,
+            action,
+            drawn,
+            points,
+            score,
+            refused,
+        } = self;
+        write!(
+            f,
+            "Turn {turn} - Player {player_id}: {action:?}, draws {drawn:?} (+{points}, total {score})"
+        )?;
+        if let Some((action, error)) = refused {
+            write!(f, ", refused {action:?}: {error}")?;
+        }
+
+        Ok(())
+    }
+}
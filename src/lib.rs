@@ -1,5 +1,9 @@
 pub mod bag;
 pub mod board;
+pub mod game;
+pub mod notation;
 pub mod player;
 pub mod rules;
+pub mod strategy;
 pub mod tile;
+pub mod tile_set;
@@ -1,58 +1,34 @@
-mod bag;
-mod board;
-mod player;
-mod rules;
-mod tile;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{env, fs, thread::sleep, time};
 
-use rand::Rng;
-use std::{thread::sleep, time};
-
-use bag::Bag;
-use board::Board;
-use player::Player;
-
-const TURNS: i8 = 51;
-
-fn create_players(bag: &mut Bag) -> Vec<Player> {
-    let mut rng = rand::rng();
-    let number = rng.random_range(2..=4);
-
-    let mut players = vec![];
-
-    for id in 1..=number {
-        let new_player = Player::new(id, bag);
-        players.push(new_player);
-    }
-
-    players
-}
+use qwirkle_solver::game::{record, Game};
 
 fn main() {
-    // create bag to draw
-    let mut bag = Bag::new();
-
-    // create players to play
-    let mut players = create_players(&mut bag);
-    for player in players.iter() {
+    // a seed can be given as first argument to replay a game
+    let seed = match env::args().nth(1) {
+        Some(seed) => seed.parse().expect("seed should be a positive integer"),
+        None => rand::random(),
+    };
+    println!("Seed: {seed}");
+
+    // create a game with a random number of players
+    let number = StdRng::seed_from_u64(seed).random_range(2..=4);
+    let mut game = Game::with_seed(number, seed);
+    for player in game.players() {
         println!("Player {}: {:?}", player.id, player.hand);
     }
 
-    // create board to play on
-    let mut board = Board::new();
-
-    'game_loop: for turn in 1..=TURNS {
+    while !game.is_over() {
+        let turn = game.turn();
         println!("=== Turn {turn} ===");
 
-        for player in players.iter_mut() {
-            let player_can_play = player.play(&mut board, &mut bag);
+        for result in game.play_turn() {
             println!(
                 "Player {} - {} points\n{:?}",
-                player.id, player.points, board
+                result.player_id,
+                result.score,
+                game.board()
             );
-            if !player_can_play {
-                println!("Player {} ended the game", player.id);
-                break 'game_loop;
-            }
         }
 
         println!("=== End of turn {turn} ===\n\n\n");
@@ -60,7 +36,17 @@ fn main() {
         sleep(time::Duration::from_millis(50));
     }
 
-    for player in players {
+    if let Some(outcome) = game.outcome() {
+        println!("{:?}", outcome.reason);
+    }
+
+    for player in game.players() {
         println!("Player {} score: {}", player.id, player.points);
     }
+
+    // a file can be given as second argument to save the game's record
+    if let (Some(path), Some(record)) = (env::args().nth(2), game.record()) {
+        fs::write(&path, record::serialize(record)).expect("record should be writable");
+        println!("Record saved to {path}");
+    }
 }
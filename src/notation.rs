@@ -0,0 +1,80 @@
+//! Compact text notation, for humans and tools to type and log moves.
+//!
+//! Every type below implements `Display` and `FromStr`, and parsing what is displayed
+//! always gives back the same value.
+//!
+//! | Type          | Notation                         | Example         |
+//! |---------------|----------------------------------|-----------------|
+//! | `Color`       | `r` `o` `y` `g` `b` `p`          | `b`             |
+//! | `Shape`       | `S` `O` `D` `C` `*` `+`          | `O`             |
+//! | `Tile`        | color then shape                 | `bO`            |
+//! | `Position`    | `x,y`                            | `3,-2`          |
+//! | `Location`    | tile `@` position                | `bO@3,-2`       |
+//! | `Direction`   | `^` (north) `>` `v` `<` (west)   | `>`             |
+//! | `PartialMove` | tiles `@` position direction     | `bObS@3,-2>`    |
+//! | `Move`        | partial move `+` points          | `bObS@3,-2>+4`  |
+//! | `Action`      | move, `exchange` tiles or `pass` | `exchange rSbO` |
+//!
+//! Colors are red, orange, yellow, green, blue and purple.
+//! Shapes are square, circle (`O`), diamond, club, star (`*`) and cross (`+`).
+//! Notation is strict: the only whitespace allowed is the one after `exchange`.
+
+use std::fmt::{Debug, Display, Formatter, Result};
+
+use crate::tile::{Tile, Tiles};
+
+/// Why a text can't be parsed, holding the offending text.
+#[derive(Clone, PartialEq, Eq)]
+pub enum NotationError {
+    Color(String),
+    Shape(String),
+    Tile(String),
+    Position(String),
+    Location(String),
+    Direction(String),
+    Move(String),
+    Action(String),
+}
+
+impl Display for NotationError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (kind, text) = match self {
+            NotationError::Color(text) => ("color", text),
+            NotationError::Shape(text) => ("shape", text),
+            NotationError::Tile(text) => ("tile", text),
+            NotationError::Position(text) => ("position", text),
+            NotationError::Location(text) => ("location", text),
+            NotationError::Direction(text) => ("direction", text),
+            NotationError::Move(text) => ("move", text),
+            NotationError::Action(text) => ("action", text),
+        };
+        write!(f, "invalid {kind} `{text}`")
+    }
+}
+
+impl Debug for NotationError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "NotationError({self})")
+    }
+}
+
+impl std::error::Error for NotationError {}
+
+/// Formats `tiles` one after the other, e.g. `bObS`.
+pub fn format_tiles(tiles: &[Tile]) -> String {
+    tiles.iter().map(|tile| tile.to_string()).collect()
+}
+
+/// Parses tiles written one after the other, e.g. `bObS`.
+/// An empty text gives no tile.
+pub fn parse_tiles(text: &str) -> std::result::Result<Tiles, NotationError> {
+    let characters = text.chars().collect::<Vec<char>>();
+    if characters.len() % 2 != 0 {
+        return Err(NotationError::Tile(text.to_owned()));
+    }
+
+    characters
+        .chunks(2)
+        .map(|pair| pair.iter().collect::<String>().parse())
+        .collect()
+}
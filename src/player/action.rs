@@ -0,0 +1,55 @@
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::board::r#move::Move;
+use crate::notation::{format_tiles, parse_tiles, NotationError};
+use crate::tile::Tiles;
+
+/// What a player did during its turn.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Action {
+    /// Tiles were placed on the board.
+    Place(Move),
+    /// Tiles chosen by the player are put back in the bag
+    /// and replaced by as many new ones.
+    Exchange(Tiles),
+    /// Nothing could be played nor exchanged.
+    Pass,
+}
+
+impl Debug for Action {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Action::Place(r#move) => write!(f, "Place {move:?}"),
+            Action::Exchange(tiles) => write!(f, "Exchange {tiles:?}"),
+            Action::Pass => write!(f, "Pass"),
+        }
+    }
+}
+
+impl Display for Action {
+    /// Writes the action in notation, e.g. `bObS@3,-2>+4`, `exchange rSbO` or `pass`,
+    /// see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Action::Place(r#move) => write!(f, "{move}"),
+            Action::Exchange(tiles) => write!(f, "exchange {}", format_tiles(tiles)),
+            Action::Pass => write!(f, "pass"),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.split_once(' ') {
+            _ if text == "pass" => Ok(Action::Pass),
+            Some(("exchange", tiles)) => Ok(Action::Exchange(parse_tiles(tiles)?)),
+            Some(_) => Err(NotationError::Action(text.to_owned())),
+            None => Ok(Action::Place(text.parse()?)),
+        }
+    }
+}
@@ -1,228 +1,287 @@
-mod moves;
+pub mod action;
+pub mod moves;
 
-use rand::Rng;
-use std::fmt::Debug;
+use rand::rngs::StdRng;
+use std::fmt::{self, Debug, Formatter};
 
 use crate::bag::Bag;
-use crate::board::direction::Direction;
-use crate::board::r#move::PartialMove;
-use crate::board::{position::Position, r#move::Move, Board};
-use crate::rules::validate_tiles;
+use crate::board::{r#move::Move, Board};
+use crate::rules::{
+    error::MoveError, ruleset::RuleSet, validate_exchange, validate_hand_move, validate_tiles,
+};
+use crate::strategy::{greedy::GreedyStrategy, Strategy, View};
 use crate::tile::{Tile, Tiles};
-use moves::get_combination_moves;
+use action::Action;
+use moves::is_playable;
 
 pub type Points = i32;
 pub type Combination = Tiles;
 pub type Combinations = Vec<Tiles>;
 
-#[derive(Debug)]
 pub struct Player {
     pub id: u8,
     pub points: Points,
     pub hand: Tiles,
     pub combinations: Combinations,
+    /// Decides which action to play on each turn.
+    pub strategy: Box<dyn Strategy>,
 }
 
 impl Player {
-    /// Constructs a new player with a hand full of 6 random tiles.
-    pub fn new(id: u8, bag: &mut Bag) -> Player {
+    /// Constructs a new player with a hand full of random tiles,
+    /// playing with `GreedyStrategy`.
+    pub fn new(id: u8, rules: &RuleSet, bag: &mut Bag, rng: &mut StdRng) -> Player {
+        Player::with_strategy(id, rules, bag, Box::new(GreedyStrategy), rng)
+    }
+
+    /// Constructs a new player with a hand full of random tiles, playing with `strategy`.
+    /// A full hand holds `rules.hand_size` tiles.
+    pub fn with_strategy(
+        id: u8,
+        rules: &RuleSet,
+        bag: &mut Bag,
+        strategy: Box<dyn Strategy>,
+        rng: &mut StdRng,
+    ) -> Player {
         let mut player = Player {
             id,
             points: 0,
             hand: Vec::new(),
             combinations: Vec::new(),
+            strategy,
         };
 
-        player.draw(bag, 6);
+        player.draw(bag, rules.hand_size, rng);
 
         player
     }
 
     /// Draws a `number` of tiles from `bag` and stores them in player's hand.
-    fn draw(&mut self, bag: &mut Bag, number: u8) {
-        let mut rng = rand::rng();
-
-        // make sure to not draw more than what the bag contains
-        let range = number.min(bag.tiles().len() as u8);
-        for _ in 0..range {
-            let max = bag.tiles().len();
-
-            // prevent `gen_range()` to panic
-            if max == 0 {
-                break;
-            }
-
-            // generate a random index and pick the associated tile
-            let index = rng.random_range(0..max);
-            if let Some(new_tile) = bag.remove(index) {
-                self.hand.push(new_tile);
-            };
-        }
+    fn draw(&mut self, bag: &mut Bag, number: usize, rng: &mut StdRng) {
+        self.hand.extend(bag.draw(number, rng));
 
         // update combinations with new tiles in hand
         self.update_combinations();
     }
 
     /// Removes the `tile` within player's hand and draws a new tile.
-    fn remove_tile(&mut self, bag: &mut Bag, tile: Tile) {
+    fn remove_tile(&mut self, bag: &mut Bag, tile: Tile, rng: &mut StdRng) {
         if let Some(index) = self.hand.iter().position(|&local_tile| local_tile == tile) {
             self.hand.remove(index);
-            self.draw(bag, 1);
+            self.draw(bag, 1, rng);
         };
     }
 
     /// Removes `tiles` within player's hand and draws new tiles.
-    fn remove_tiles(&mut self, bag: &mut Bag, combination: Combination) {
+    fn remove_tiles(&mut self, bag: &mut Bag, combination: Combination, rng: &mut StdRng) {
         for tile in combination {
-            self.remove_tile(bag, tile);
+            self.remove_tile(bag, tile, rng);
         }
     }
 
-    /// Removes from hand a random number of tiles,
-    /// draws as many new tiles and put back removed tiles in `bag`.
-    fn replace(&mut self, bag: &mut Bag) {
-        let mut rng = rand::rng();
-
-        // generate number of tiles to replace
-        let length = (self.hand.len() as u8).max(1);
-        let number = rng.random_range(1..=length);
-
-        let mut tiles = Vec::new();
-        for _ in 0..number {
-            let length = self.hand.len();
-            if length == 0 {
-                break;
+    /// Removes `tiles` from hand, draws as many new tiles
+    /// and only then puts back removed tiles in `bag`.
+    fn exchange(&mut self, bag: &mut Bag, tiles: &Tiles, rng: &mut StdRng) {
+        for tile in tiles {
+            if let Some(index) = self.hand.iter().position(|local_tile| local_tile == tile) {
+                self.hand.remove(index);
             }
-
-            // generate a random index and remove a random tile from hand
-            let index = rng.random_range(0..length);
-            let tile = self.hand.remove(index);
-            tiles.push(tile);
         }
 
-        // draw new tiles from bag
-        self.draw(bag, number);
+        // draw new tiles from bag, then put back exchanged tiles
+        self.hand.extend(bag.exchange(tiles.clone(), rng));
+        self.update_combinations();
+    }
 
-        // add replaced tiles to bag
-        bag.add(tiles);
+    /// Lets player's strategy choose an action and plays it. Returns the played `Action`,
+    /// or why it was refused if the strategy chose an illegal action, nothing being played.
+    ///
+    /// When the player has no tile left in hand after playing,
+    /// the game is over and the player receives `rules.end_bonus` points.
+    pub fn play(
+        &mut self,
+        rules: &RuleSet,
+        board: &mut Board,
+        bag: &mut Bag,
+        rng: &mut StdRng,
+    ) -> Result<Action, MoveError> {
+        let scores = [(self.id, self.points)];
+        let hand_sizes = [self.hand.len()];
+        let action = self.choose_action(rules, board, bag.len(), &scores, &hand_sizes, rng);
+        self.apply(&action, rules, board, bag, rng)?;
+
+        Ok(action)
     }
 
-    /// Finds the best move and plays it.
-    pub fn play(&mut self, board: &mut Board, bag: &mut Bag) -> bool {
-        if board.tiles().len() == 0 {
-            // if board is empty, play the longest combination
-            let mut lengths = self
-                .combinations
-                .iter()
-                .enumerate()
-                .map(|(index, combination)| (index, combination.len()))
-                .collect::<Vec<(usize, usize)>>();
-            lengths.sort_by(|a, b| a.1.cmp(&b.1));
-
-            // play it in the center
-            if let Some(&(index, length)) = lengths.last() {
-                let combination = self.combinations[index].clone();
-
-                board.add_tiles(&PartialMove {
-                    combination: combination.clone(),
-                    position: Position { x: 0, y: 0 },
-                    direction: Direction::rand(),
-                });
-
-                self.points += length as Points;
-
-                self.remove_tiles(bag, combination);
-            } else {
-                eprintln!(
-                    "Player.play() -> can't first any combination to play on first move: {:#?}",
-                    self.combinations
-                );
-            }
-        } else {
-            // get every possible moves
-            let moves = self.get_moves(board);
+    /// Asks player's strategy which action to play,
+    /// knowing the `rules`, the `board`, the `bag_size`,
+    /// and `scores` and `hand_sizes` of every player.
+    pub fn choose_action(
+        &mut self,
+        rules: &RuleSet,
+        board: &Board,
+        bag_size: usize,
+        scores: &[(u8, Points)],
+        hand_sizes: &[usize],
+        rng: &mut StdRng,
+    ) -> Action {
+        let view = View {
+            player_id: self.id,
+            rules,
+            board,
+            hand: &self.hand,
+            bag_size,
+            scores,
+            hand_sizes,
+        };
+
+        self.strategy.choose(&view, rng)
+    }
 
-            // get last tiles from possible moves
-            // last = best move, highest amount of points
-            // TODO: based the latest highest score, randomly select a move with the same score
-            if let Some(last_move) = moves.last() {
-                let (partial_move, points) = last_move.into_partial();
+    /// Validates and applies an `action` to `board`, `bag` and player's hand.
+    /// Returns points earned, including end of game bonus.
+    pub fn apply(
+        &mut self,
+        action: &Action,
+        rules: &RuleSet,
+        board: &mut Board,
+        bag: &mut Bag,
+        rng: &mut StdRng,
+    ) -> Result<Points, MoveError> {
+        match action {
+            Action::Place(r#move) => {
+                let (partial_move, _) = r#move.clone().into_partial();
+                let Move { points, .. } =
+                    validate_hand_move(rules, board, &self.hand, partial_move.clone())?;
 
                 // play move by adding tiles to the board
-                board.add_tiles(&partial_move);
-
-                // increase points
-                self.points += points;
+                board.add_tiles(&partial_move)?;
 
                 // remove combination from hand
-                self.remove_tiles(bag, partial_move.combination);
-            } else {
-                // can't find any tile to play, replace some tiles
-                self.replace(bag);
-            };
-        }
+                self.remove_tiles(bag, partial_move.combination, rng);
+
+                // final move of the game rewards bonus points
+                let bonus = if self.hand.is_empty() {
+                    rules.end_bonus
+                } else {
+                    0
+                };
+
+                // increase points
+                self.points += points + bonus;
 
-        // check if player has still tiles in hand
-        if self.hand.len() > 0 {
-            true
-        } else {
-            // final move of the game rewards 6 bonus points
-            self.points += 6;
+                Ok(points + bonus)
+            }
+            Action::Exchange(tiles) => {
+                validate_exchange(&self.hand, bag.len(), tiles)?;
+                self.exchange(bag, tiles, rng);
 
-            false
+                Ok(0)
+            }
+            Action::Pass => Ok(0),
         }
     }
 
-    /// Finds all playable locations with associated points to gain.
-    fn get_moves(&self, board: &Board) -> Vec<Move> {
-        // for every combination from player's hand
-        let mut moves = self
-            .combinations
+    /// Returns `true` if at least one tile of player's hand can be placed on `board`.
+    pub fn can_play(&self, rules: &RuleSet, board: &Board) -> bool {
+        self.hand
             .iter()
-            .filter_map(|combination| get_combination_moves(board, combination))
-            .flatten()
-            .collect::<Vec<Move>>();
-
-        // sort `moves` to find best (= latest)
-        moves.sort();
+            .any(|&tile| is_playable(rules, board, tile))
+    }
 
-        moves
+    /// Returns the longest combination of tiles sharing a color or a shape
+    /// within player's hand, i.e. the combination to open the game with.
+    pub fn best_opening(&self) -> Option<&Combination> {
+        longest_combination(&self.combinations)
     }
 
     /// Updates player's `combinations` based on current `hand` state.
     /// Must be call after every hand update (e.g. `.draw()`).
     pub fn update_combinations(&mut self) {
-        // for each tile in hand, compute combinations
-        // wrap the tile in `Vec<Tile>` to create a one tile combination
-        self.combinations = self
-            .hand
-            .iter()
-            .flat_map(|&tile| self.compute_combinations(&vec![tile], &self.hand))
-            .collect::<Combinations>();
+        self.combinations = get_combinations(&self.hand);
     }
+}
 
-    /// For one `combination`, recursively computes every possible combination with `tiles`.
-    /// It returns entry `combination` in addition with found combinations.
-    /// So if no combination is found, returned vector has at least one element.
-    fn compute_combinations(&self, combination: &Combination, tiles: &Tiles) -> Combinations {
-        let combination_clone = vec![combination.clone()];
+impl Debug for Player {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Player {
+            id, points, hand, ..
+        } = self;
+        write!(f, "Player {id} ({points} points): {hand:?}")
+    }
+}
 
-        // for each tile
-        let new_combinations: Combinations = tiles
-            .iter()
-            // validate that `tile` can be added to the `combination`
-            .filter(|&tile| combination.iter().all(|t| validate_tiles(t, tile)))
-            .flat_map(|tile| {
-                // add `tile` to `combination` by creating a `new_combination`
-                let mut new_combination: Combination = combination.clone();
-                new_combination.push(*tile);
-
-                // keep checking if `new_combination` can create more combinations
-                self.compute_combinations(&new_combination, &tiles)
-            })
-            .collect::<Combinations>();
-
-        // concat entry `combination` with `new_combinations`
-        [combination_clone, new_combinations].concat()
+/// A player is serialized as its id, points and hand, see `PlayerSnapshot`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Player {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let snapshot = crate::game::snapshot::PlayerSnapshot {
+            id: self.id,
+            points: self.points,
+            hand: self.hand.clone(),
+        };
+        snapshot.serialize(serializer)
     }
 }
+
+/// A deserialized player plays with `GreedyStrategy`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Player {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let crate::game::snapshot::PlayerSnapshot { id, points, hand } =
+            serde::Deserialize::deserialize(deserializer)?;
+
+        let mut player = Player {
+            id,
+            points,
+            hand,
+            combinations: Vec::new(),
+            strategy: Box::new(GreedyStrategy),
+        };
+        player.update_combinations();
+
+        Ok(player)
+    }
+}
+
+/// Computes every combination of tiles from `hand`.
+pub fn get_combinations(hand: &Tiles) -> Combinations {
+    // for each tile in hand, compute combinations
+    // wrap the tile in `Vec<Tile>` to create a one tile combination
+    hand.iter()
+        .flat_map(|&tile| compute_combinations(&vec![tile], hand))
+        .collect::<Combinations>()
+}
+
+/// Returns the longest of `combinations`. On a tie, the first one is returned.
+pub fn longest_combination(combinations: &Combinations) -> Option<&Combination> {
+    combinations
+        .iter()
+        .rev()
+        .max_by_key(|combination| combination.len())
+}
+
+/// For one `combination`, recursively computes every possible combination with `tiles`.
+/// It returns entry `combination` in addition with found combinations.
+/// So if no combination is found, returned vector has at least one element.
+fn compute_combinations(combination: &Combination, tiles: &Tiles) -> Combinations {
+    let combination_clone = vec![combination.clone()];
+
+    // for each tile
+    let new_combinations: Combinations = tiles
+        .iter()
+        // validate that `tile` can be added to the `combination`
+        .filter(|&tile| combination.iter().all(|t| validate_tiles(t, tile)))
+        .flat_map(|tile| {
+            // add `tile` to `combination` by creating a `new_combination`
+            let mut new_combination: Combination = combination.clone();
+            new_combination.push(*tile);
+
+            // keep checking if `new_combination` can create more combinations
+            compute_combinations(&new_combination, tiles)
+        })
+        .collect::<Combinations>();
+
+    // concat entry `combination` with `new_combinations`
+    [combination_clone, new_combinations].concat()
+}
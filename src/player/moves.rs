@@ -5,13 +5,52 @@ use crate::board::{
     r#move::{Move, Moves, PartialMove},
     Board,
 };
-use crate::rules::{validate_partial_move, validate_tiles};
+use crate::rules::{ruleset::RuleSet, validate_partial_move, validate_tiles};
+use crate::tile::Tile;
 
-use super::Combination;
+use super::{Combination, Combinations};
+
+/// Finds all playable moves for `combinations` with associated points to gain.
+/// Moves are sorted by points, the best move being the last one.
+pub fn get_moves(rules: &RuleSet, board: &Board, combinations: &Combinations) -> Moves {
+    // for every combination from player's hand
+    let mut moves = combinations
+        .iter()
+        .filter_map(|combination| get_combination_moves(rules, board, combination))
+        .flatten()
+        .collect::<Moves>();
+
+    // sort `moves` to find best (= latest)
+    moves.sort();
+
+    moves
+}
 
 /// Returns `moves` for a given `combination`.
 /// Compares the `combination` to every tile on the booard.
-pub fn get_combination_moves(board: &Board, combination: &Combination) -> Option<Moves> {
+/// On an empty board, only opening moves in the center are returned, one per direction.
+pub fn get_combination_moves(
+    rules: &RuleSet,
+    board: &Board,
+    combination: &Combination,
+) -> Option<Moves> {
+    if board.tiles().is_empty() {
+        let opening_moves = Direction::values()
+            .iter()
+            .filter_map(|&direction| {
+                get_move(
+                    rules,
+                    board,
+                    combination,
+                    Position { x: 0, y: 0 },
+                    direction,
+                )
+            })
+            .collect();
+
+        return Some(opening_moves);
+    }
+
     let combination_moves = board
         .tiles()
         .iter()
@@ -24,7 +63,7 @@ pub fn get_combination_moves(board: &Board, combination: &Combination) -> Option
             }
 
             // returns `moves` for this `location`
-            get_combination_moves_near_location(board, combination, location)
+            get_combination_moves_near_location(rules, board, combination, location)
         })
         .flatten()
         .collect::<Vec<Move>>();
@@ -33,20 +72,23 @@ pub fn get_combination_moves(board: &Board, combination: &Combination) -> Option
     Some(combination_moves)
 }
 
+/// Returns `true` if `tile` can be placed alone somewhere on the `board`.
+pub fn is_playable(rules: &RuleSet, board: &Board, tile: Tile) -> bool {
+    get_combination_moves(rules, board, &vec![tile]).is_some_and(|moves| !moves.is_empty())
+}
+
 /// Returns `moves` for a given `combination` next to a given `location`.
 /// Finds every position close to `location` and tries to place the `combination`.
 fn get_combination_moves_near_location(
+    rules: &RuleSet,
     board: &Board,
     combination: &Combination,
     location: &Location,
 ) -> Option<Moves> {
     let moves = Direction::values()
         .iter()
-        .filter_map(|location_direction| {
-            let position = Position {
-                x: location.position.x + location_direction.0,
-                y: location.position.y + location_direction.1,
-            };
+        .filter_map(|&location_direction| {
+            let position = location.position.step(location_direction, 1)?;
 
             // find neighbor to check if it's free to drop a tile
             let neighbor = board.get(position.x, position.y);
@@ -55,7 +97,13 @@ fn get_combination_moves_near_location(
             }
 
             // returns `moves` for this `position`
-            get_combination_moves_near_location_at_position(board, combination, location, position)
+            get_combination_moves_near_location_at_position(
+                rules,
+                board,
+                combination,
+                position,
+                location_direction,
+            )
         })
         .flatten()
         .collect::<Moves>();
@@ -64,20 +112,20 @@ fn get_combination_moves_near_location(
     Some(moves)
 }
 
-/// Returns `moves` for a given `combination` at a given `position`, which is next to a given `location`.
+/// Returns `moves` for a given `combination` at a given `position`,
+/// which is next to a location, going from the location to `location_direction`.
+/// As moves step over tiles already on the board, going through location's line
+/// finds moves filling gaps or extending the line on both sides.
 fn get_combination_moves_near_location_at_position(
+    rules: &RuleSet,
     board: &Board,
     combination: &Combination,
-    location: &Location,
     position: Position,
+    location_direction: Direction,
 ) -> Option<Moves> {
     let get_one_move = || -> Moves {
-        let direction = Direction(
-            position.x - location.position.x,
-            position.y - location.position.y,
-        );
-
-        let Some(one_move) = get_move(board, combination, position, direction) else {
+        let Some(one_move) = get_move(rules, board, combination, position, location_direction)
+        else {
             return Vec::new();
         };
 
@@ -87,7 +135,7 @@ fn get_combination_moves_near_location_at_position(
     let get_all_moves = || -> Moves {
         Direction::values()
             .iter()
-            .filter_map(|&direction| get_move(board, combination, position, direction))
+            .filter_map(|&direction| get_move(rules, board, combination, position, direction))
             .collect::<Moves>()
     };
 
@@ -104,6 +152,7 @@ fn get_combination_moves_near_location_at_position(
 
 /// Validates a (partial) move and get points from it.
 fn get_move(
+    rules: &RuleSet,
     board: &Board,
     combination: &Combination,
     position: Position,
@@ -114,5 +163,5 @@ fn get_move(
         position,
         direction,
     };
-    validate_partial_move(board, partial_move)
+    validate_partial_move(rules, board, partial_move).ok()
 }
@@ -0,0 +1,158 @@
+use std::fmt::{Debug, Display, Formatter, Result};
+
+use crate::board::{error::BoardError, position::Position};
+use crate::tile::{Tiles, COLORS_NUMBER, SHAPES_NUMBER};
+
+/// Why a move is illegal.
+///
+/// When the error comes from the board, offending positions are attached,
+/// e.g. to highlight them in a frontend.
+#[derive(Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// The move or exchange doesn't contain any tile.
+    Empty,
+    /// First move of the game must cover `(0, 0)`.
+    FirstMoveNotAtOrigin(Position),
+    /// Tiles would be placed on these already taken positions.
+    Occupied(Vec<Position>),
+    /// Tiles would be placed on these positions, beyond `Position::MIN` or `Position::MAX`.
+    OutOfRange(Vec<Position>),
+    /// None of the tiles, placed at these positions, touches a tile already on the board.
+    NotConnected(Vec<Position>),
+    /// Tiles at these positions aren't all in the same row or column.
+    NotAligned(Vec<Position>),
+    /// These positions between placed tiles would stay empty.
+    Gap(Vec<Position>),
+    /// The same tile appears twice in a line, at these positions.
+    DuplicateTile(Vec<Position>),
+    /// These tiles share neither their color nor their shape but are in the same line.
+    MixedAttributes(Vec<Position>),
+    /// A line would be longer than a Qwirkle, at these positions.
+    LineTooLong(Vec<Position>),
+    /// These tiles aren't in player's hand.
+    NotInHand(Tiles),
+    /// The bag holds fewer tiles than the number of exchanged tiles.
+    BagTooSmall { exchanged: usize, available: usize },
+    /// The game is already over, nothing can be played anymore.
+    GameOver,
+}
+
+impl MoveError {
+    /// Returns positions related to the error, if any.
+    pub fn positions(&self) -> &[Position] {
+        match self {
+            MoveError::FirstMoveNotAtOrigin(position) => std::slice::from_ref(position),
+            MoveError::Occupied(positions)
+            | MoveError::OutOfRange(positions)
+            | MoveError::NotConnected(positions)
+            | MoveError::NotAligned(positions)
+            | MoveError::Gap(positions)
+            | MoveError::DuplicateTile(positions)
+            | MoveError::MixedAttributes(positions)
+            | MoveError::LineTooLong(positions) => positions,
+            MoveError::Empty
+            | MoveError::NotInHand(_)
+            | MoveError::BagTooSmall { .. }
+            | MoveError::GameOver => &[],
+        }
+    }
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            MoveError::Empty => write!(f, "move doesn't contain any tile"),
+            MoveError::FirstMoveNotAtOrigin(position) => {
+                write!(
+                    f,
+                    "first move must cover (0, 0), starting from {position:?}"
+                )
+            }
+            MoveError::Occupied(positions) => write!(f, "positions already taken: {positions:?}"),
+            MoveError::OutOfRange(positions) => write!(f, "positions out of range: {positions:?}"),
+            MoveError::NotConnected(positions) => {
+                write!(f, "tiles not connected to the board: {positions:?}")
+            }
+            MoveError::NotAligned(positions) => {
+                write!(f, "tiles not in the same row or column: {positions:?}")
+            }
+            MoveError::Gap(positions) => write!(f, "empty positions between tiles: {positions:?}"),
+            MoveError::DuplicateTile(positions) => {
+                write!(f, "same tile twice in a line: {positions:?}")
+            }
+            MoveError::MixedAttributes(positions) => {
+                write!(f, "tiles share neither color nor shape: {positions:?}")
+            }
+            MoveError::LineTooLong(positions) => {
+                write!(f, "line longer than a Qwirkle: {positions:?}")
+            }
+            MoveError::NotInHand(tiles) => write!(f, "tiles not in hand: {tiles:?}"),
+            MoveError::BagTooSmall {
+                exchanged,
+                available,
+            } => write!(
+                f,
+                "can't exchange {exchanged} tiles with only {available} tiles in the bag"
+            ),
+            MoveError::GameOver => write!(f, "game is over"),
+        }
+    }
+}
+
+impl Debug for MoveError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "MoveError({self})")
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+impl From<BoardError> for MoveError {
+    fn from(error: BoardError) -> Self {
+        match error {
+            BoardError::Occupied(position) => MoveError::Occupied(vec![position]),
+            BoardError::OutOfRange(position) => MoveError::OutOfRange(vec![position]),
+        }
+    }
+}
+
+/// Why rules can't be played, see `RuleSet::validate()`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RuleSetError {
+    /// Tiles can't have this many colors, which must be between 1 and `COLORS_NUMBER`.
+    Colors(usize),
+    /// Tiles can't have this many shapes, which must be between 1 and `SHAPES_NUMBER`.
+    Shapes(usize),
+    /// The bag must hold at least one copy of each tile.
+    NoCopies,
+    /// Players must hold at least one tile.
+    EmptyHand,
+    /// A Qwirkle can't be this long, as lines are at least 2 tiles long.
+    QwirkleLength(usize),
+}
+
+impl Display for RuleSetError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            RuleSetError::Colors(number) => {
+                write!(f, "{number} colors, expected 1 to {COLORS_NUMBER}")
+            }
+            RuleSetError::Shapes(number) => {
+                write!(f, "{number} shapes, expected 1 to {SHAPES_NUMBER}")
+            }
+            RuleSetError::NoCopies => write!(f, "no copy of tiles in the bag"),
+            RuleSetError::EmptyHand => write!(f, "no tile in hands"),
+            RuleSetError::QwirkleLength(length) => {
+                write!(f, "Qwirkle of {length} tiles, expected at least 2")
+            }
+        }
+    }
+}
+
+impl Debug for RuleSetError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "RuleSetError({self})")
+    }
+}
+
+impl std::error::Error for RuleSetError {}
@@ -0,0 +1,281 @@
+pub mod error;
+pub mod ruleset;
+
+use std::iter;
+
+use crate::board::{
+    location::Location, placement::Placement, position::Position, r#move::Move,
+    r#move::PartialMove, Board,
+};
+use crate::player::{Combination, Points};
+use crate::tile::{Tile, Tiles};
+use error::MoveError;
+use ruleset::RuleSet;
+
+/// Validates combination between two tiles.
+///
+/// To be valid, a combination must have either the same color or the same shape
+/// but not both at the same time.
+pub fn validate_tiles(tile0: &Tile, tile1: &Tile) -> bool {
+    let Tile {
+        color: color0,
+        shape: shape0,
+    } = tile0;
+    let Tile {
+        color: color1,
+        shape: shape1,
+    } = tile1;
+
+    let is_combinable = color0 == color1 || shape0 == shape1;
+    let is_same_tile = color0 == color1 && shape0 == shape1;
+
+    is_combinable && !is_same_tile
+}
+
+/// Validates an alignement of tiles. It checks that every tile can be combined together.
+///
+/// An alignement can't contains a tile twice
+/// and tiles can only have the same color or the same shape.
+/// > Note: an alignement can't be longer than a Qwirkle, see `RuleSet::qwirkle_length`.
+fn validate_alignement(rules: &RuleSet, alignement: &[Location]) -> Result<(), MoveError> {
+    let positions = || alignement.iter().map(|location| location.position);
+
+    if alignement.len() > rules.qwirkle_length {
+        return Err(MoveError::LineTooLong(positions().collect()));
+    }
+
+    for (index, location) in alignement.iter().enumerate() {
+        for next_location in alignement[(index + 1)..].iter() {
+            if validate_tiles(&location.tile, &next_location.tile) {
+                continue;
+            }
+
+            let offending = vec![location.position, next_location.position];
+            return if location.tile == next_location.tile {
+                Err(MoveError::DuplicateTile(offending))
+            } else {
+                Err(MoveError::MixedAttributes(offending))
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a `partial_move` and returns how many points it gives.
+/// Returns a `Move`, i.e. `partial_move` + `points`, or why the move is illegal.
+///
+/// Tiles are placed as described by `PartialMove::placement()`,
+/// then validated by `validate_placement()`.
+pub fn validate_partial_move(
+    rules: &RuleSet,
+    board: &Board,
+    partial_move: PartialMove,
+) -> Result<Move, MoveError> {
+    let placement = partial_move.placement(board)?;
+    let points = validate_placement(rules, board, &placement)?;
+
+    Ok(partial_move.into_move(points))
+}
+
+/// Validates a `placement` and returns how many points it gives, or why it is illegal.
+///
+/// First it checks that there is free space to place it
+/// and that every tile is in the same row or column, without any gap.
+/// Then it builds every alignement created by the placement,
+/// checks that it touches tiles already on the board, and validates alignements.
+pub fn validate_placement(
+    rules: &RuleSet,
+    board: &Board,
+    placement: &Placement,
+) -> Result<Points, MoveError> {
+    let locations = &placement.locations;
+    let positions = placement.positions();
+
+    let Some(&first) = locations.first() else {
+        return Err(MoveError::Empty);
+    };
+
+    // the first move of the game is played in the center
+    if board.tiles().is_empty() && !positions.contains(&Position { x: 0, y: 0 }) {
+        return Err(MoveError::FirstMoveNotAtOrigin(first.position));
+    }
+
+    // validate that every tile is within the board
+    let out_of_range = positions
+        .iter()
+        .filter(|position| !position.is_in_range())
+        .copied()
+        .collect::<Vec<Position>>();
+    if !out_of_range.is_empty() {
+        return Err(MoveError::OutOfRange(out_of_range));
+    }
+
+    // validate that there is free space for every tile
+    let occupied = positions
+        .iter()
+        .enumerate()
+        .filter(|&(index, position)| {
+            board.get(position.x, position.y).is_some() || positions[..index].contains(position)
+        })
+        .map(|(_, &position)| position)
+        .collect::<Vec<Position>>();
+    if !occupied.is_empty() {
+        return Err(MoveError::Occupied(occupied));
+    }
+
+    // every tile must be in the same line
+    let Some(direction) = placement.direction() else {
+        return Err(MoveError::NotAligned(positions));
+    };
+
+    // build main alignement which is following the placement's line,
+    // from the first to the last tile, filling in-between positions with the board
+    let main_alignement = {
+        // projected in `i32`, as spans of far apart tiles don't fit in a `Coordinate`
+        let coordinate = |position: &Position| {
+            position.x as i32 * direction.0 as i32 + position.y as i32 * direction.1 as i32
+        };
+        let start = *positions.iter().min_by_key(|&p| coordinate(p)).unwrap();
+        let end = *positions.iter().max_by_key(|&p| coordinate(p)).unwrap();
+
+        let mut before = board.get_locations(start, direction.opposite());
+        before.reverse();
+
+        // tiles too far apart can't be in the same line, which also keeps the walk below short
+        let span = coordinate(&end) - coordinate(&start);
+        if span as usize + 1 > rules.qwirkle_length {
+            return Err(MoveError::LineTooLong(positions.clone()));
+        }
+
+        // every position between `start` and `end` is in range, as both are
+        let walk = iter::successors(Some(start), |&position| {
+            (position != end)
+                .then(|| position.step(direction, 1))
+                .flatten()
+        });
+
+        let mut between = Vec::new();
+        let mut gaps = Vec::new();
+        for position in walk {
+            if let Some(&location) = locations.iter().find(|l| l.position == position) {
+                between.push(location);
+            } else if let Some(tile) = board.get(position.x, position.y) {
+                between.push(Location { position, tile });
+            } else {
+                gaps.push(position);
+            }
+        }
+        if !gaps.is_empty() {
+            return Err(MoveError::Gap(gaps));
+        }
+
+        let after = board.get_locations(end, direction);
+
+        [before, between, after].concat()
+    };
+
+    // for every placed tile, build perpendiculars alignements (left + right)
+    let perpendicular_alignements = {
+        let perpendicular = direction.perpendicular();
+        let perpendicular_opposite = perpendicular.opposite();
+
+        locations
+            .iter()
+            .filter_map(|&location| {
+                let mut before = board.get_locations(location.position, perpendicular_opposite);
+                before.reverse();
+                let after = board.get_locations(location.position, perpendicular);
+                let alignement = [before, vec![location], after].concat();
+
+                if alignement.len() == 1 {
+                    // if the tile isn't aligned with anything,
+                    // don't return it to avoid useless vec and computations
+                    None
+                } else {
+                    Some(alignement)
+                }
+            })
+            .collect::<Vec<Vec<Location>>>()
+    };
+
+    // except for the first move, at least one tile must be next to a tile of the board,
+    // so either the main alignement or a perpendicular one goes beyond placed tiles
+    let is_connected =
+        main_alignement.len() > locations.len() || !perpendicular_alignements.is_empty();
+    if !board.tiles().is_empty() && !is_connected {
+        return Err(MoveError::NotConnected(positions));
+    }
+
+    // validate all alignements to validate the move
+    let alignements = [vec![main_alignement], perpendicular_alignements].concat();
+    for alignement in alignements.iter() {
+        validate_alignement(rules, alignement)?;
+    }
+
+    // a lone tile only scores when opening the game,
+    // otherwise only lines of at least two tiles score
+    let points = alignements
+        .iter()
+        .map(|alignement| rules.line_points(alignement.len()))
+        .sum::<Points>();
+
+    Ok(points.max(1))
+}
+
+/// Validates that every tile of `combination` is in `hand`.
+/// A tile used twice in `combination` must be twice in `hand`.
+pub fn validate_hand(hand: &Tiles, combination: &Combination) -> Result<(), MoveError> {
+    let mut remaining = hand.clone();
+
+    let missing = combination
+        .iter()
+        .filter(|&tile| match remaining.iter().position(|t| t == tile) {
+            Some(index) => {
+                remaining.remove(index);
+                false
+            }
+            None => true,
+        })
+        .copied()
+        .collect::<Tiles>();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(MoveError::NotInHand(missing))
+    }
+}
+
+/// Validates a `partial_move` played from `hand`, see `validate_partial_move()`.
+pub fn validate_hand_move(
+    rules: &RuleSet,
+    board: &Board,
+    hand: &Tiles,
+    partial_move: PartialMove,
+) -> Result<Move, MoveError> {
+    validate_hand(hand, &partial_move.combination)?;
+
+    validate_partial_move(rules, board, partial_move)
+}
+
+/// Validates an exchange of `tiles` from `hand`.
+///
+/// Exchanged tiles must be in `hand`,
+/// and the bag, holding `bag_size` tiles, must be able to replace all of them.
+pub fn validate_exchange(hand: &Tiles, bag_size: usize, tiles: &Tiles) -> Result<(), MoveError> {
+    if tiles.is_empty() {
+        return Err(MoveError::Empty);
+    }
+
+    validate_hand(hand, tiles)?;
+
+    if bag_size < tiles.len() {
+        return Err(MoveError::BagTooSmall {
+            exchanged: tiles.len(),
+            available: bag_size,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use crate::player::Points;
+use crate::tile::{Color, Shape, Tile, COLORS_NUMBER, SHAPES_NUMBER, SHAPES_REPETITION};
+use crate::tile_set::TileSet;
+
+use super::error::RuleSetError;
+
+/// Parameters of a game, to play variants without recompiling.
+///
+/// Variants play with the first `colors_number` colors of `Color::values()`
+/// and the first `shapes_number` shapes of `Shape::values()`,
+/// so each one is at most 6, see `validate()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedRuleSet"))]
+pub struct RuleSet {
+    /// How many colors tiles can have.
+    pub colors_number: usize,
+    /// How many shapes tiles can have.
+    pub shapes_number: usize,
+    /// How many copies of each color and shape pair the bag holds.
+    pub copies: usize,
+    /// How many tiles a player holds.
+    pub hand_size: usize,
+    /// Length of a complete line, i.e. a Qwirkle. No line can be longer.
+    pub qwirkle_length: usize,
+    /// Points earned on top of the line's length when completing a Qwirkle.
+    pub qwirkle_bonus: Points,
+    /// Points earned by the player who empties its hand to end the game.
+    pub end_bonus: Points,
+}
+
+impl Default for RuleSet {
+    /// Rules of the original game: 6 colors, 6 shapes, 3 copies of each tile,
+    /// 6 tiles in hand and 6 bonus points for a Qwirkle or ending the game.
+    fn default() -> Self {
+        RuleSet {
+            colors_number: COLORS_NUMBER,
+            shapes_number: SHAPES_NUMBER,
+            copies: SHAPES_REPETITION,
+            hand_size: 6,
+            qwirkle_length: 6,
+            qwirkle_bonus: 6,
+            end_bonus: 6,
+        }
+    }
+}
+
+impl RuleSet {
+    /// Constructs rules for a smaller game with `attributes_number` colors and shapes,
+    /// e.g. 4 for a 4×4 variant: hands, Qwirkles and bonuses shrink accordingly.
+    pub fn square(attributes_number: usize) -> RuleSet {
+        RuleSet {
+            colors_number: attributes_number,
+            shapes_number: attributes_number,
+            hand_size: attributes_number,
+            qwirkle_length: attributes_number,
+            qwirkle_bonus: attributes_number as Points,
+            end_bonus: attributes_number as Points,
+            ..RuleSet::default()
+        }
+    }
+
+    /// Checks that a game can be played with these rules:
+    /// there must be between 1 and 6 colors and shapes, at least one copy of each tile,
+    /// one tile in hands, and a Qwirkle must be at least 2 tiles long.
+    pub fn validate(&self) -> Result<(), RuleSetError> {
+        if !(1..=COLORS_NUMBER).contains(&self.colors_number) {
+            return Err(RuleSetError::Colors(self.colors_number));
+        }
+        if !(1..=SHAPES_NUMBER).contains(&self.shapes_number) {
+            return Err(RuleSetError::Shapes(self.shapes_number));
+        }
+        if self.copies == 0 {
+            return Err(RuleSetError::NoCopies);
+        }
+        if self.hand_size == 0 {
+            return Err(RuleSetError::EmptyHand);
+        }
+        if self.qwirkle_length < 2 {
+            return Err(RuleSetError::QwirkleLength(self.qwirkle_length));
+        }
+
+        Ok(())
+    }
+
+    /// Returns colors tiles can have.
+    pub fn colors(&self) -> Vec<Color> {
+        Color::values()
+            .into_iter()
+            .take(self.colors_number)
+            .collect()
+    }
+
+    /// Returns shapes tiles can have.
+    pub fn shapes(&self) -> Vec<Shape> {
+        Shape::values()
+            .into_iter()
+            .take(self.shapes_number)
+            .collect()
+    }
+
+    /// Returns every tile of a game played with these rules.
+    pub fn tile_set(&self) -> TileSet {
+        let mut set = TileSet::new();
+        for color in self.colors() {
+            for shape in self.shapes() {
+                set.add_copies(Tile { color, shape }, self.copies);
+            }
+        }
+
+        set
+    }
+
+    /// Returns how many tiles the bag holds at the beginning of a game.
+    pub fn bag_size(&self) -> usize {
+        self.colors_number * self.shapes_number * self.copies
+    }
+
+    /// Returns points earned by a line of `length` tiles, including the Qwirkle bonus.
+    /// A lone tile doesn't form a line.
+    pub fn line_points(&self, length: usize) -> Points {
+        match length {
+            0 | 1 => 0,
+            length if length == self.qwirkle_length => length as Points + self.qwirkle_bonus,
+            length => length as Points,
+        }
+    }
+}
+
+/// Rules as deserialized, before being validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedRuleSet {
+    colors_number: usize,
+    shapes_number: usize,
+    copies: usize,
+    hand_size: usize,
+    qwirkle_length: usize,
+    qwirkle_bonus: Points,
+    end_bonus: Points,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedRuleSet> for RuleSet {
+    type Error = RuleSetError;
+
+    fn try_from(rules: UncheckedRuleSet) -> Result<Self, Self::Error> {
+        let UncheckedRuleSet {
+            colors_number,
+            shapes_number,
+            copies,
+            hand_size,
+            qwirkle_length,
+            qwirkle_bonus,
+            end_bonus,
+        } = rules;
+        let rules = RuleSet {
+            colors_number,
+            shapes_number,
+            copies,
+            hand_size,
+            qwirkle_length,
+            qwirkle_bonus,
+            end_bonus,
+        };
+        rules.validate()?;
+
+        Ok(rules)
+    }
+}
@@ -0,0 +1,343 @@
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::board::{location::Location, Board};
+use crate::player::{action::Action, get_combinations, moves::get_moves, Points};
+use crate::rules::ruleset::RuleSet;
+use crate::tile::Tiles;
+use crate::tile_set::TileSet;
+
+use super::{greedy::GreedyStrategy, Strategy, View};
+
+/// Solves two-player endgames exactly, once the bag is empty
+/// and both hands are known.
+///
+/// Searches every sequence of moves with alpha-beta pruning,
+/// remembering positions already solved, and counts the end bonus
+/// earned by the player who empties its hand.
+/// Players are assumed to pass only when they can't place any tile.
+/// The search gives up after `max_nodes` positions, or once `time` is elapsed if set.
+#[derive(Debug, Clone, Copy)]
+pub struct EndgameSolver {
+    /// Maximum number of positions searched.
+    pub max_nodes: usize,
+    /// Maximum duration of the search.
+    pub time: Option<Duration>,
+}
+
+impl Default for EndgameSolver {
+    fn default() -> Self {
+        EndgameSolver {
+            max_nodes: 200_000,
+            time: None,
+        }
+    }
+}
+
+/// Optimal play from an endgame position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    /// Points the player to move earns until the end of the game,
+    /// minus points its opponent earns, both playing optimally.
+    pub value: Points,
+    /// Actions played until the end of the game, both players playing optimally,
+    /// the first one being the player to move's.
+    pub actions: Vec<Action>,
+}
+
+impl EndgameSolver {
+    /// Solves the endgame on `board` where the bag is empty,
+    /// `hands[0]` belonging to the player to move and `hands[1]` to its opponent.
+    /// Returns `None` if the search hits its limits first.
+    pub fn solve(&self, rules: &RuleSet, board: &Board, hands: [Tiles; 2]) -> Option<Solution> {
+        let mut search = Search {
+            rules,
+            board: board.clone(),
+            hands,
+            start: board.tiles().len(),
+            table: HashMap::new(),
+            nodes: 0,
+            max_nodes: self.max_nodes,
+            deadline: self.time.map(|time| Instant::now() + time),
+        };
+
+        let value = search.negamax(0, -INFINITY, INFINITY, false)?;
+        let actions = search.principal_variation();
+
+        Some(Solution { value, actions })
+    }
+}
+
+/// Plays endgames exactly with `solver` once the bag is empty in a two-player game,
+/// see `EndgameSolver`, and plays as `fallback` otherwise,
+/// including when the solver hits its limits.
+pub struct EndgameStrategy {
+    pub solver: EndgameSolver,
+    pub fallback: Box<dyn Strategy>,
+}
+
+impl Default for EndgameStrategy {
+    fn default() -> Self {
+        EndgameStrategy::new(Box::new(GreedyStrategy))
+    }
+}
+
+impl EndgameStrategy {
+    /// Constructs a strategy playing as `fallback` until the endgame can be solved.
+    pub fn new(fallback: Box<dyn Strategy>) -> EndgameStrategy {
+        EndgameStrategy {
+            solver: EndgameSolver::default(),
+            fallback,
+        }
+    }
+}
+
+impl Strategy for EndgameStrategy {
+    fn choose(&mut self, view: &View, rng: &mut StdRng) -> Action {
+        if view.bag_size == 0 && view.scores.len() == 2 {
+            // every unseen tile is in the opponent's hand
+            let opponent = view.unseen_tiles().tiles();
+            let hands = [view.hand.clone(), opponent];
+            let solution = self.solver.solve(view.rules, view.board, hands);
+
+            if let Some(action) = solution.and_then(|solution| solution.actions.first().cloned()) {
+                return action;
+            }
+        }
+
+        self.fallback.choose(view, rng)
+    }
+}
+
+/// Greater than any difference of points in an endgame.
+const INFINITY: Points = 1_000_000;
+
+/// How a remembered value relates to the exact value of a position.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    /// The exact value is at least this value.
+    Lower,
+    /// The exact value is at most this value.
+    Upper,
+}
+
+/// What is remembered about a solved position.
+struct Entry {
+    value: Points,
+    bound: Bound,
+    /// Best action found, if any tile can be placed.
+    best: Option<Action>,
+}
+
+/// A position: tiles placed since the search started, both hands and the player to move.
+type Key = (Vec<Location>, [TileSet; 2], usize);
+
+/// State of a search, whose board and hands are updated as moves are tried.
+struct Search<'a> {
+    rules: &'a RuleSet,
+    board: Board,
+    hands: [Tiles; 2],
+    /// How many tiles were on the board when the search started.
+    start: usize,
+    table: HashMap<Key, Entry>,
+    nodes: usize,
+    max_nodes: usize,
+    deadline: Option<Instant>,
+}
+
+impl Search<'_> {
+    /// Returns the value of the position for `current` player,
+    /// exact if it lies between `alpha` and `beta`, or a bound otherwise.
+    /// `passed` tells if the previous player couldn't place any tile.
+    /// Returns `None` once the search hits its limits.
+    fn negamax(
+        &mut self,
+        current: usize,
+        mut alpha: Points,
+        mut beta: Points,
+        passed: bool,
+    ) -> Option<Points> {
+        self.nodes += 1;
+        if self.nodes > self.max_nodes {
+            return None;
+        }
+        if self.deadline.is_some_and(|time| Instant::now() >= time) {
+            return None;
+        }
+
+        let moves = get_moves(
+            self.rules,
+            &self.board,
+            &get_combinations(&self.hands[current]),
+        );
+        if moves.is_empty() {
+            // nobody can place a tile anymore: the game is blocked
+            if passed {
+                return Some(0);
+            }
+
+            return self
+                .negamax(1 - current, -beta, -alpha, true)
+                .map(|value| -value);
+        }
+
+        let key = self.key(current);
+        if let Some(entry) = self.table.get(&key) {
+            match entry.bound {
+                Bound::Exact => return Some(entry.value),
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return Some(entry.value);
+            }
+        }
+
+        let original_alpha = alpha;
+        let mut best_value = -INFINITY;
+        let mut best = None;
+
+        // best scoring moves first, to prune more
+        for r#move in moves.into_iter().rev() {
+            let Ok(token) = self.board.apply(&r#move) else {
+                continue;
+            };
+            self.take(current, &r#move.combination);
+
+            let value = if self.hands[current].is_empty() {
+                Some(r#move.points + self.rules.end_bonus)
+            } else {
+                let points = r#move.points;
+                self.negamax(1 - current, points - beta, points - alpha, false)
+                    .map(|reply| points - reply)
+            };
+
+            self.give_back(current, &r#move.combination);
+            self.board.undo(token);
+
+            let value = value?;
+            if value > best_value {
+                best_value = value;
+                best = Some(Action::Place(r#move));
+            }
+            alpha = alpha.max(value);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best_value <= original_alpha {
+            Bound::Upper
+        } else if best_value >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        // a bound, found with a narrower window, never replaces an exact value
+        let is_exact = self
+            .table
+            .get(&key)
+            .is_some_and(|entry| entry.bound == Bound::Exact);
+        if bound == Bound::Exact || !is_exact {
+            self.table.insert(
+                key,
+                Entry {
+                    value: best_value,
+                    bound,
+                    best,
+                },
+            );
+        }
+
+        Some(best_value)
+    }
+
+    /// Returns actions following best actions remembered from the initial position.
+    /// Positions only known by a bound are searched again with a full window,
+    /// so that every action of the line is optimal.
+    /// These searches get limits of their own, and the line stops early if they hit them.
+    fn principal_variation(&mut self) -> Vec<Action> {
+        self.nodes = 0;
+        let mut actions = Vec::new();
+        let mut current = 0;
+        let mut passed = false;
+
+        loop {
+            let has_moves = !get_moves(
+                self.rules,
+                &self.board,
+                &get_combinations(&self.hands[current]),
+            )
+            .is_empty();
+            if !has_moves {
+                if passed {
+                    break;
+                }
+                actions.push(Action::Pass);
+                current = 1 - current;
+                passed = true;
+                continue;
+            }
+
+            let key = self.key(current);
+            let is_exact = self
+                .table
+                .get(&key)
+                .is_some_and(|entry| entry.bound == Bound::Exact);
+            if !is_exact {
+                self.table.remove(&key);
+                if self.negamax(current, -INFINITY, INFINITY, passed).is_none() {
+                    break;
+                }
+            }
+
+            let Some(Action::Place(r#move)) =
+                self.table.get(&key).and_then(|entry| entry.best.clone())
+            else {
+                break;
+            };
+            if self.board.apply(&r#move).is_err() {
+                break;
+            }
+            self.take(current, &r#move.combination);
+            actions.push(Action::Place(r#move));
+
+            if self.hands[current].is_empty() {
+                break;
+            }
+            current = 1 - current;
+            passed = false;
+        }
+
+        actions
+    }
+
+    /// Returns the key of the position where `current` player is to move.
+    fn key(&self, current: usize) -> Key {
+        let mut placed = self.board.tiles()[self.start..].to_vec();
+        placed.sort_by_key(|location| (location.position.x, location.position.y));
+        let hands = [
+            self.hands[0].iter().copied().collect(),
+            self.hands[1].iter().copied().collect(),
+        ];
+
+        (placed, hands, current)
+    }
+
+    /// Removes `tiles` from `current` player's hand.
+    fn take(&mut self, current: usize, tiles: &Tiles) {
+        let hand = &mut self.hands[current];
+        for tile in tiles {
+            if let Some(index) = hand.iter().position(|t| t == tile) {
+                hand.swap_remove(index);
+            }
+        }
+    }
+
+    /// Gives `tiles` back to `current` player.
+    fn give_back(&mut self, current: usize, tiles: &Tiles) {
+        self.hands[current].extend(tiles);
+    }
+}
@@ -0,0 +1,55 @@
+use rand::rngs::StdRng;
+
+use crate::player::{
+    action::Action, get_combinations, longest_combination, moves::get_moves, Combinations,
+};
+use crate::tile::Tiles;
+
+use super::{Strategy, View};
+
+/// Plays the move giving the highest amount of points,
+/// or exchanges tiles if no move can be played.
+pub struct GreedyStrategy;
+
+impl Strategy for GreedyStrategy {
+    fn choose(&mut self, view: &View, _rng: &mut StdRng) -> Action {
+        // get every possible moves
+        // if board is empty, they are opening moves played in the center
+        let combinations = get_combinations(view.hand);
+        let moves = get_moves(view.rules, view.board, &combinations);
+
+        // get last tiles from possible moves
+        // last = best move, highest amount of points
+        // TODO: based the latest highest score, randomly select a move with the same score
+        if let Some(last_move) = moves.last() {
+            Action::Place(last_move.clone())
+        } else if view.bag_size == 0 {
+            // can't find any tile to play nor to exchange
+            Action::Pass
+        } else {
+            // can't find any tile to play, exchange some tiles
+            Action::Exchange(choose_exchange(view.hand, &combinations, view.bag_size))
+        }
+    }
+}
+
+/// Chooses tiles to exchange from `hand`: keeps the longest combination
+/// and exchanges every other tile, within the limit of `bag_size` tiles.
+/// If the whole hand is one combination, every tile is exchanged.
+pub fn choose_exchange(hand: &Tiles, combinations: &Combinations, bag_size: usize) -> Tiles {
+    let mut tiles = hand.clone();
+    if let Some(kept) = longest_combination(combinations) {
+        for tile in kept {
+            if let Some(index) = tiles.iter().position(|t| t == tile) {
+                tiles.remove(index);
+            }
+        }
+    }
+
+    if tiles.is_empty() {
+        tiles = hand.clone();
+    }
+    tiles.truncate(bag_size);
+
+    tiles
+}
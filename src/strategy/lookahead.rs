@@ -0,0 +1,182 @@
+use rand::{rngs::StdRng, seq::IndexedRandom};
+
+use crate::board::Board;
+use crate::player::{action::Action, get_combinations, moves::get_moves};
+use crate::rules::ruleset::RuleSet;
+use crate::tile::Tiles;
+
+use super::{greedy::GreedyStrategy, Strategy, View};
+
+/// Plays the move maximising its points minus what the next player can expect to earn in reply.
+///
+/// The next player's hand is unknown, so their best reply is averaged over `samples` hands
+/// drawn among unseen tiles. Beyond two plies, each reply is itself valued the same way,
+/// every later hand being sampled among unseen tiles, including the player's own next draws.
+/// With more than two players, each player is assumed to play against the next one only.
+/// A move emptying the hand once the bag is empty ends the game: it earns the end bonus
+/// and nobody replies.
+///
+/// If no move can be played, plays as `GreedyStrategy`.
+#[derive(Debug, Clone, Copy)]
+pub struct LookaheadStrategy {
+    /// How many plies are looked at, including the player's own move:
+    /// 1 plays as `GreedyStrategy`, 2 accounts for the next player's reply.
+    pub depth: usize,
+    /// How many hands are sampled to estimate each reply.
+    pub samples: usize,
+    /// How many of the best scoring moves are considered in each position.
+    pub branching: usize,
+}
+
+impl Default for LookaheadStrategy {
+    fn default() -> Self {
+        LookaheadStrategy {
+            depth: 2,
+            samples: 12,
+            branching: 6,
+        }
+    }
+}
+
+impl LookaheadStrategy {
+    /// Constructs a lookahead of `depth` plies.
+    pub fn with_depth(depth: usize) -> LookaheadStrategy {
+        LookaheadStrategy {
+            depth,
+            ..LookaheadStrategy::default()
+        }
+    }
+}
+
+impl Strategy for LookaheadStrategy {
+    fn choose(&mut self, view: &View, rng: &mut StdRng) -> Action {
+        if self.depth <= 1 {
+            return GreedyStrategy.choose(view, rng);
+        }
+
+        // the next player in turn order replies
+        let index = view
+            .scores
+            .iter()
+            .position(|&(id, _)| id == view.player_id)
+            .unwrap_or_default();
+        let next_hand_size = view
+            .hand_sizes
+            .get((index + 1) % view.hand_sizes.len().max(1))
+            .copied()
+            .unwrap_or(view.rules.hand_size);
+
+        let unseen = view.unseen_tiles().tiles();
+        let mut search = Search {
+            strategy: *self,
+            rules: view.rules,
+            board: view.board.clone(),
+            rng,
+        };
+        let best = search.best_gain(
+            view.hand,
+            &unseen,
+            next_hand_size,
+            view.bag_size,
+            self.depth,
+        );
+
+        match best {
+            Some((action, _)) => action,
+            None => GreedyStrategy.choose(view, rng),
+        }
+    }
+}
+
+/// State of a search from one position, whose board is updated as moves are tried.
+struct Search<'a> {
+    strategy: LookaheadStrategy,
+    rules: &'a RuleSet,
+    board: Board,
+    rng: &'a mut StdRng,
+}
+
+impl Search<'_> {
+    /// Returns points the next player can expect to earn over `depth` plies,
+    /// minus what following players can expect in reply,
+    /// their hand of `hand_size` tiles being drawn from `unseen` tiles,
+    /// `bag_size` of which are in the bag.
+    fn expected_gain(
+        &mut self,
+        unseen: &Tiles,
+        hand_size: usize,
+        bag_size: usize,
+        depth: usize,
+    ) -> f64 {
+        if depth == 0 || unseen.is_empty() {
+            return 0.;
+        }
+
+        let samples = self.strategy.samples;
+        let mut total = 0.;
+        for _ in 0..samples {
+            let hand = unseen
+                .choose_multiple(self.rng, hand_size)
+                .copied()
+                .collect::<Tiles>();
+            let pool = remaining(unseen, &hand);
+
+            // a player who can't place tiles earns nothing
+            let best = self.best_gain(&hand, &pool, self.rules.hand_size, bag_size, depth);
+            if let Some((_, gain)) = best {
+                total += gain;
+            }
+        }
+
+        total / samples.max(1) as f64
+    }
+
+    /// Returns the move of `hand` maximising its points minus the next player's expected gain,
+    /// looking `depth` plies ahead, with this difference.
+    /// The next player holds `next_hand_size` tiles, and the bag `bag_size` tiles.
+    /// Returns `None` if `hand` can't be placed.
+    fn best_gain(
+        &mut self,
+        hand: &Tiles,
+        unseen: &Tiles,
+        next_hand_size: usize,
+        bag_size: usize,
+        depth: usize,
+    ) -> Option<(Action, f64)> {
+        let moves = get_moves(self.rules, &self.board, &get_combinations(hand));
+
+        let mut best: Option<(Action, f64)> = None;
+        for r#move in moves.into_iter().rev().take(self.strategy.branching) {
+            let gain = if bag_size == 0 && r#move.combination.len() == hand.len() {
+                // emptying the hand with an empty bag ends the game
+                (r#move.points + self.rules.end_bonus) as f64
+            } else {
+                let Ok(token) = self.board.apply(&r#move) else {
+                    continue;
+                };
+                let drawn = r#move.combination.len().min(bag_size);
+                let reply = self.expected_gain(unseen, next_hand_size, bag_size - drawn, depth - 1);
+                self.board.undo(token);
+
+                r#move.points as f64 - reply
+            };
+            if best.as_ref().is_none_or(|&(_, best_gain)| gain > best_gain) {
+                best = Some((Action::Place(r#move), gain));
+            }
+        }
+
+        best
+    }
+}
+
+/// Returns `tiles` without one copy of each tile of `removed`.
+fn remaining(tiles: &Tiles, removed: &Tiles) -> Tiles {
+    let mut remaining = tiles.clone();
+    for tile in removed {
+        if let Some(index) = remaining.iter().position(|t| t == tile) {
+            remaining.swap_remove(index);
+        }
+    }
+
+    remaining
+}
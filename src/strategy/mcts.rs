@@ -0,0 +1,231 @@
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng};
+use std::time::{Duration, Instant};
+
+use crate::game::Game;
+use crate::player::{action::Action, moves::get_moves, Points};
+
+use super::{
+    greedy::{choose_exchange, GreedyStrategy},
+    Strategy, View,
+};
+
+/// Chooses actions with a Monte Carlo Tree Search over hidden information.
+///
+/// Each iteration samples opponents' hands and the bag among unseen tiles
+/// (see `View::sample_snapshot()`), walks down a tree shared by every sample,
+/// only following actions legal in this sample, then finishes with a playout
+/// where every player plays with `GreedyStrategy`.
+/// The action played is the most visited one.
+///
+/// The search stops after `iterations` iterations, or once `time` is elapsed if set.
+/// Only a search bounded by `iterations` can be reproduced from a seed.
+#[derive(Debug, Clone, Copy)]
+pub struct MctsStrategy {
+    /// Maximum number of iterations per action.
+    pub iterations: usize,
+    /// Maximum duration of the search per action.
+    pub time: Option<Duration>,
+    /// How many actions a playout lasts at most, after leaving the tree.
+    pub playout_length: usize,
+    /// How many of the best scoring moves are considered in each position.
+    pub branching: usize,
+    /// Weight of exploration against exploitation when walking down the tree.
+    pub exploration: f64,
+}
+
+impl Default for MctsStrategy {
+    fn default() -> Self {
+        MctsStrategy {
+            iterations: 1000,
+            time: None,
+            playout_length: 8,
+            branching: 8,
+            exploration: std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl MctsStrategy {
+    /// Constructs a search stopping after `iterations` iterations.
+    pub fn with_iterations(iterations: usize) -> MctsStrategy {
+        MctsStrategy {
+            iterations,
+            ..MctsStrategy::default()
+        }
+    }
+
+    /// Constructs a search stopping once `time` is elapsed.
+    pub fn with_time(time: Duration) -> MctsStrategy {
+        MctsStrategy {
+            iterations: usize::MAX,
+            time: Some(time),
+            ..MctsStrategy::default()
+        }
+    }
+
+    /// Runs one iteration from a sampled position, updating `tree`.
+    fn iterate(&self, tree: &mut Vec<Node>, view: &View, rng: &mut StdRng) {
+        let snapshot = view.sample_snapshot(rng);
+        let strategies = snapshot
+            .players
+            .iter()
+            .map(|_| Box::new(GreedyStrategy) as Box<dyn Strategy>)
+            .collect();
+        let mut game = Game::from_snapshot(snapshot, *view.rules, strategies, rng.random());
+
+        // selection and expansion
+        let mut path = vec![0];
+        let mut node = 0;
+        while !game.is_over() {
+            let actions = self.candidates(&game);
+            let children = tree[node].children.clone();
+
+            let untried = actions
+                .iter()
+                .filter(|action| !children.iter().any(|&child| tree[child].action == **action))
+                .collect::<Vec<&Action>>();
+            if let Some(&action) = untried.choose(rng) {
+                let child = tree.len();
+                tree.push(Node::new(action.clone(), game.current_player().id));
+                tree[node].children.push(child);
+                path.push(child);
+                game.play_action(action.clone())
+                    .expect("candidate actions should be legal");
+                break;
+            }
+
+            let available = children
+                .into_iter()
+                .filter(|&child| actions.contains(&tree[child].action))
+                .collect::<Vec<usize>>();
+            for &child in &available {
+                tree[child].availability += 1;
+            }
+
+            let Some(&child) = available.iter().max_by(|&&a, &&b| {
+                let (a, b) = (tree[a].ucb(self.exploration), tree[b].ucb(self.exploration));
+                a.total_cmp(&b)
+            }) else {
+                break;
+            };
+
+            node = child;
+            path.push(node);
+            game.play_action(tree[node].action.clone())
+                .expect("candidate actions should be legal");
+        }
+
+        // playout
+        for _ in 0..self.playout_length {
+            if game.step().is_none() {
+                break;
+            }
+        }
+
+        // backpropagation
+        let scores = game.scores();
+        for &node in &path {
+            let node = &mut tree[node];
+            node.visits += 1;
+            node.reward += reward(&scores, node.player_id);
+        }
+    }
+
+    /// Returns actions considered for the current player of `game`:
+    /// the best scoring moves, or an exchange or a pass if no move can be played.
+    fn candidates(&self, game: &Game) -> Vec<Action> {
+        let player = game.current_player();
+        let moves = get_moves(game.rules(), game.board(), &player.combinations);
+        if !moves.is_empty() {
+            return moves
+                .into_iter()
+                .rev()
+                .take(self.branching)
+                .map(Action::Place)
+                .collect();
+        }
+
+        let bag_size = game.bag().len();
+        if bag_size == 0 {
+            vec![Action::Pass]
+        } else {
+            let tiles = choose_exchange(&player.hand, &player.combinations, bag_size);
+            vec![Action::Exchange(tiles)]
+        }
+    }
+}
+
+impl Strategy for MctsStrategy {
+    fn choose(&mut self, view: &View, rng: &mut StdRng) -> Action {
+        let start = Instant::now();
+        let mut tree = vec![Node::new(Action::Pass, view.player_id)];
+
+        for _ in 0..self.iterations {
+            if self.time.is_some_and(|time| start.elapsed() >= time) {
+                break;
+            }
+            self.iterate(&mut tree, view, rng);
+        }
+
+        tree[0]
+            .children
+            .iter()
+            .max_by_key(|&&child| tree[child].visits)
+            .map(|&child| tree[child].action.clone())
+            .unwrap_or_else(|| GreedyStrategy.choose(view, rng))
+    }
+}
+
+/// An action in the search tree, with statistics of iterations that played it.
+struct Node {
+    action: Action,
+    /// Id of the player who plays `action`.
+    player_id: u8,
+    children: Vec<usize>,
+    visits: u32,
+    /// How many times the action could be played when its parent was visited.
+    availability: u32,
+    /// Sum of rewards of the player who plays `action`.
+    reward: f64,
+}
+
+impl Node {
+    fn new(action: Action, player_id: u8) -> Node {
+        Node {
+            action,
+            player_id,
+            children: Vec::new(),
+            visits: 0,
+            availability: 1,
+            reward: 0.,
+        }
+    }
+
+    /// Returns the upper confidence bound of the action's reward.
+    fn ucb(&self, exploration: f64) -> f64 {
+        let visits = self.visits.max(1) as f64;
+        let availability = self.availability.max(1) as f64;
+
+        self.reward / visits + exploration * (availability.ln() / visits).sqrt()
+    }
+}
+
+/// Points ahead of the best opponent for which a player is considered as good as winning.
+const LEAD_SCALE: f64 = 20.;
+
+/// Returns the reward of `player_id` for final `scores`, between 0 and 1:
+/// 0.5 when tied with the best opponent, closer to 1 the further ahead.
+fn reward(scores: &[(u8, Points)], player_id: u8) -> f64 {
+    let points = scores
+        .iter()
+        .find(|&&(id, _)| id == player_id)
+        .map_or(0, |&(_, points)| points);
+    let best_opponent = scores
+        .iter()
+        .filter(|&&(id, _)| id != player_id)
+        .map(|&(_, points)| points)
+        .max()
+        .unwrap_or_default();
+
+    0.5 + 0.5 * ((points - best_opponent) as f64 / LEAD_SCALE).tanh()
+}
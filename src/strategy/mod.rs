@@ -0,0 +1,89 @@
+pub mod endgame;
+pub mod greedy;
+pub mod lookahead;
+pub mod mcts;
+pub mod tracker;
+
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+use crate::board::Board;
+use crate::game::snapshot::{PlayerSnapshot, Snapshot};
+use crate::player::{action::Action, Points};
+use crate::rules::ruleset::RuleSet;
+use crate::tile::Tiles;
+use crate::tile_set::TileSet;
+
+/// What a player knows when choosing an action.
+pub struct View<'a> {
+    /// Id of the player choosing an action.
+    pub player_id: u8,
+    /// Rules of the game being played.
+    pub rules: &'a RuleSet,
+    pub board: &'a Board,
+    /// Tiles in the player's hand.
+    pub hand: &'a Tiles,
+    /// How many tiles are left in the bag.
+    pub bag_size: usize,
+    /// Score of every player id, in turn order.
+    pub scores: &'a [(u8, Points)],
+    /// How many tiles every player holds, in the same order as `scores`.
+    pub hand_sizes: &'a [usize],
+}
+
+impl View<'_> {
+    /// Returns tiles the player hasn't seen: tiles of the game
+    /// which are neither on the board nor in the player's hand,
+    /// i.e. tiles in the bag or in opponents' hands.
+    pub fn unseen_tiles(&self) -> TileSet {
+        let mut unseen = self.rules.tile_set();
+        let seen = self.board.tiles().iter().map(|location| location.tile);
+        for tile in seen.chain(self.hand.iter().copied()) {
+            unseen.remove(tile);
+        }
+
+        unseen
+    }
+
+    /// Samples a position consistent with what the player knows:
+    /// unseen tiles are shuffled and dealt to opponents, the remaining ones make the bag.
+    /// The player is the next one to play.
+    pub fn sample_snapshot(&self, rng: &mut StdRng) -> Snapshot {
+        let mut unseen = self.unseen_tiles().tiles();
+        unseen.shuffle(rng);
+
+        let players = self
+            .scores
+            .iter()
+            .zip(self.hand_sizes)
+            .map(|(&(id, points), &hand_size)| {
+                let hand = if id == self.player_id {
+                    self.hand.clone()
+                } else {
+                    let kept = unseen.len().saturating_sub(hand_size);
+                    unseen.split_off(kept)
+                };
+
+                PlayerSnapshot { id, points, hand }
+            })
+            .collect::<Vec<PlayerSnapshot>>();
+
+        let current = players
+            .iter()
+            .position(|player| player.id == self.player_id)
+            .unwrap_or_default();
+
+        Snapshot {
+            board: self.board.clone(),
+            players,
+            bag: unseen,
+            current,
+        }
+    }
+}
+
+/// Decides which action a player plays.
+pub trait Strategy {
+    /// Chooses an action to play from what the player knows.
+    /// Any random decision must be drawn from `rng`, so games can be reproduced.
+    fn choose(&mut self, view: &View, rng: &mut StdRng) -> Action;
+}
@@ -0,0 +1,153 @@
+use crate::board::{
+    analysis::{self, Line},
+    Board,
+};
+use crate::game::turn::TurnResult;
+use crate::player::action::Action;
+use crate::rules::ruleset::RuleSet;
+use crate::tile::Tile;
+use crate::tile_set::TileSet;
+
+use super::View;
+
+/// Counts tiles a player hasn't seen, from public information only:
+/// tiles placed on the board, how many tiles opponents exchange, and the player's own draws.
+///
+/// A tracker is started from what the player knows with `new()`,
+/// then follows the game with `observe()` after every turn.
+#[derive(Debug, Clone)]
+pub struct TileTracker {
+    rules: RuleSet,
+    player_id: u8,
+    /// Tiles neither on the board nor in the player's hand.
+    unseen: TileSet,
+    /// Tiles in the player's hand.
+    hand: TileSet,
+    /// How many tiles every player holds, in turn order.
+    hand_sizes: Vec<(u8, usize)>,
+    bag_size: usize,
+}
+
+impl TileTracker {
+    /// Constructs a tracker from what a player knows at any moment of a game.
+    pub fn new(view: &View) -> TileTracker {
+        TileTracker {
+            rules: *view.rules,
+            player_id: view.player_id,
+            unseen: view.unseen_tiles(),
+            hand: view.hand.iter().copied().collect(),
+            hand_sizes: view
+                .scores
+                .iter()
+                .map(|&(id, _)| id)
+                .zip(view.hand_sizes.iter().copied())
+                .collect(),
+            bag_size: view.bag_size,
+        }
+    }
+
+    /// Updates the tracker with a turn played by anyone.
+    /// Tiles drawn are only looked at on the player's own turns.
+    pub fn observe(&mut self, result: &TurnResult) {
+        let own = result.player_id == self.player_id;
+
+        match &result.action {
+            Action::Place(r#move) => {
+                let placed = r#move.combination.len();
+                for &tile in &r#move.combination {
+                    if own {
+                        self.hand.remove(tile);
+                    } else {
+                        self.unseen.remove(tile);
+                    }
+                }
+
+                let drawn = placed.min(self.bag_size);
+                self.bag_size -= drawn;
+                if let Some((_, size)) = self
+                    .hand_sizes
+                    .iter_mut()
+                    .find(|(id, _)| *id == result.player_id)
+                {
+                    *size = *size + drawn - placed;
+                }
+            }
+            // exchanged tiles go back to the bag, unseen
+            Action::Exchange(tiles) if own => {
+                for &tile in tiles {
+                    self.hand.remove(tile);
+                    self.unseen.add(tile);
+                }
+            }
+            Action::Exchange(_) | Action::Pass => {}
+        }
+
+        if own {
+            for &tile in &result.drawn {
+                self.unseen.remove(tile);
+                self.hand.add(tile);
+            }
+        }
+    }
+
+    /// Returns tiles neither on the board nor in the player's hand,
+    /// i.e. tiles in the bag or in opponents' hands.
+    pub fn unseen_tiles(&self) -> &TileSet {
+        &self.unseen
+    }
+
+    /// Returns how many copies of `tile` are still unseen.
+    pub fn unseen(&self, tile: Tile) -> usize {
+        self.unseen.count(tile)
+    }
+
+    /// Returns how many copies of `tile` can still be placed,
+    /// whoever holds them: unseen ones and the ones in the player's hand.
+    pub fn available(&self, tile: Tile) -> usize {
+        self.unseen.count(tile) + self.hand.count(tile)
+    }
+
+    /// Returns how many tiles are left in the bag.
+    pub fn bag_size(&self) -> usize {
+        self.bag_size
+    }
+
+    /// Returns how many tiles the player `player_id` holds,
+    /// or `None` if there is no such player.
+    pub fn hand_size(&self, player_id: u8) -> Option<usize> {
+        self.hand_sizes
+            .iter()
+            .find(|&&(id, _)| id == player_id)
+            .map(|&(_, size)| size)
+    }
+
+    /// Returns the probability that the opponent `player_id` holds at least one copy of `tile`,
+    /// every unseen tile being as likely to be in its hand as in the bag.
+    /// Returns 0 for the player itself or an unknown player.
+    pub fn holding_probability(&self, player_id: u8, tile: Tile) -> f64 {
+        if player_id == self.player_id {
+            return 0.;
+        }
+        let Some(hand_size) = self.hand_size(player_id) else {
+            return 0.;
+        };
+
+        // probability that none of the hand's tiles is a copy of `tile`
+        let unseen = self.unseen.len();
+        let others = unseen - self.unseen(tile);
+        let none = (0..hand_size.min(unseen))
+            .map(|drawn| others.saturating_sub(drawn) as f64 / (unseen - drawn) as f64)
+            .product::<f64>();
+
+        1. - none
+    }
+
+    /// Returns lines of the board which can't become a Qwirkle anymore,
+    /// because too few of their missing tiles are left to be placed, see `Line::is_dead()`.
+    pub fn dead_lines(&self, board: &Board) -> Vec<Line> {
+        analysis::lines(&self.rules, board)
+            .into_iter()
+            .filter(|line| line.is_dead(&self.rules))
+            .collect()
+    }
+}
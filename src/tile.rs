@@ -1,27 +1,60 @@
-use std::fmt::{Debug, Formatter, Result};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::notation::NotationError;
 
 pub const COLORS_NUMBER: usize = 6;
 pub const SHAPES_NUMBER: usize = 6;
 // How many of the same shape there are in one set of color.
 pub const SHAPES_REPETITION: usize = 3;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tile {
     pub color: Color,
     pub shape: Shape,
 }
 
 impl Debug for Tile {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let Tile { color, shape } = self;
 
         write!(f, "{color:?}{shape:?}")
     }
 }
 
+impl Display for Tile {
+    /// Writes the tile in notation, e.g. `rS` for a red square, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Tile { color, shape } = self;
+
+        write!(f, "{color}{shape}")
+    }
+}
+
+impl FromStr for Tile {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut characters = text.chars();
+        let (Some(color), Some(shape), None) =
+            (characters.next(), characters.next(), characters.next())
+        else {
+            return Err(NotationError::Tile(text.to_owned()));
+        };
+
+        Ok(Tile {
+            color: color.to_string().parse()?,
+            shape: shape.to_string().parse()?,
+        })
+    }
+}
+
 pub type Tiles = Vec<Tile>;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Color {
     Red,    // 🟥
     Orange, // 🟧
@@ -31,8 +64,27 @@ pub enum Color {
     Purple, // 🟪
 }
 
+impl Color {
+    /// Returns every color, in a fixed order.
+    pub fn values() -> [Color; COLORS_NUMBER] {
+        [
+            Color::Red,
+            Color::Orange,
+            Color::Yellow,
+            Color::Green,
+            Color::Blue,
+            Color::Purple,
+        ]
+    }
+
+    /// Returns the index of the color within `Color::values()`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 impl Debug for Color {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let color: &str = match self {
             Color::Red => "\x1b[30;41m",
             Color::Orange => "\x1b[30;48;2;255;127;0m",
@@ -45,7 +97,40 @@ impl Debug for Color {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+impl Display for Color {
+    /// Writes the color's initial, e.g. `r` for red, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let character = match self {
+            Color::Red => 'r',
+            Color::Orange => 'o',
+            Color::Yellow => 'y',
+            Color::Green => 'g',
+            Color::Blue => 'b',
+            Color::Purple => 'p',
+        };
+        write!(f, "{character}")
+    }
+}
+
+impl FromStr for Color {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "r" => Ok(Color::Red),
+            "o" => Ok(Color::Orange),
+            "y" => Ok(Color::Yellow),
+            "g" => Ok(Color::Green),
+            "b" => Ok(Color::Blue),
+            "p" => Ok(Color::Purple),
+            _ => Err(NotationError::Color(text.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Shape {
     Square,  // □
     Circle,  // ◯
@@ -55,8 +140,27 @@ pub enum Shape {
     Cross,   // +
 }
 
+impl Shape {
+    /// Returns every shape, in a fixed order.
+    pub fn values() -> [Shape; SHAPES_NUMBER] {
+        [
+            Shape::Square,
+            Shape::Circle,
+            Shape::Diamond,
+            Shape::Club,
+            Shape::Star,
+            Shape::Cross,
+        ]
+    }
+
+    /// Returns the index of the shape within `Shape::values()`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 impl Debug for Shape {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let shape: &str = match self {
             Shape::Square => " □ \x1b[39;49m",
             Shape::Circle => " ◯ \x1b[39;49m",
@@ -68,3 +172,34 @@ impl Debug for Shape {
         write!(f, "{}", shape)
     }
 }
+
+impl Display for Shape {
+    /// Writes one character for the shape, e.g. `S` for square, see `notation`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let character = match self {
+            Shape::Square => 'S',
+            Shape::Circle => 'O',
+            Shape::Diamond => 'D',
+            Shape::Club => 'C',
+            Shape::Star => '*',
+            Shape::Cross => '+',
+        };
+        write!(f, "{character}")
+    }
+}
+
+impl FromStr for Shape {
+    type Err = NotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "S" => Ok(Shape::Square),
+            "O" => Ok(Shape::Circle),
+            "D" => Ok(Shape::Diamond),
+            "C" => Ok(Shape::Club),
+            "*" => Ok(Shape::Star),
+            "+" => Ok(Shape::Cross),
+            _ => Err(NotationError::Shape(text.to_owned())),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use crate::tile::{Color, Shape, Tile, Tiles, COLORS_NUMBER, SHAPES_NUMBER, SHAPES_REPETITION};
+
+/// A multiset of tiles: how many copies of each color and shape pair it holds.
+///
+/// Order doesn't matter, which makes it handy to compare tiles spread
+/// between the bag, the hands and the board with the full set of the game.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "Tiles", from = "Tiles"))]
+pub struct TileSet {
+    counts: [[usize; SHAPES_NUMBER]; COLORS_NUMBER],
+}
+
+impl TileSet {
+    /// Constructs an empty set.
+    pub fn new() -> TileSet {
+        TileSet::default()
+    }
+
+    /// Constructs the full set of a game: 3 copies of each color and shape pair.
+    /// See `RuleSet::tile_set()` for variants.
+    pub fn full() -> TileSet {
+        TileSet {
+            counts: [[SHAPES_REPETITION; SHAPES_NUMBER]; COLORS_NUMBER],
+        }
+    }
+
+    /// Returns how many copies of `tile` the set holds.
+    pub fn count(&self, tile: Tile) -> usize {
+        self.counts[tile.color.index()][tile.shape.index()]
+    }
+
+    /// Returns how many tiles the set holds.
+    pub fn len(&self) -> usize {
+        self.counts.iter().flatten().sum()
+    }
+
+    /// Returns `true` if the set doesn't hold any tile.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a copy of `tile`.
+    pub fn add(&mut self, tile: Tile) {
+        self.add_copies(tile, 1);
+    }
+
+    /// Adds `number` copies of `tile`.
+    pub fn add_copies(&mut self, tile: Tile, number: usize) {
+        self.counts[tile.color.index()][tile.shape.index()] += number;
+    }
+
+    /// Removes a copy of `tile`. Returns `false` if there is none.
+    pub fn remove(&mut self, tile: Tile) -> bool {
+        let count = &mut self.counts[tile.color.index()][tile.shape.index()];
+        if *count == 0 {
+            return false;
+        }
+
+        *count -= 1;
+        true
+    }
+
+    /// Returns every tile of the set, sorted by color then shape.
+    pub fn tiles(&self) -> Tiles {
+        Color::values()
+            .into_iter()
+            .flat_map(|color| Shape::values().map(|shape| Tile { color, shape }))
+            .flat_map(|tile| std::iter::repeat_n(tile, self.count(tile)))
+            .collect()
+    }
+}
+
+impl Extend<Tile> for TileSet {
+    fn extend<I: IntoIterator<Item = Tile>>(&mut self, tiles: I) {
+        for tile in tiles {
+            self.add(tile);
+        }
+    }
+}
+
+impl FromIterator<Tile> for TileSet {
+    fn from_iter<I: IntoIterator<Item = Tile>>(tiles: I) -> Self {
+        let mut set = TileSet::new();
+        set.extend(tiles);
+
+        set
+    }
+}
+
+impl From<Tiles> for TileSet {
+    fn from(tiles: Tiles) -> Self {
+        tiles.into_iter().collect()
+    }
+}
+
+impl From<TileSet> for Tiles {
+    fn from(set: TileSet) -> Self {
+        set.tiles()
+    }
+}
+
+impl Debug for TileSet {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:?}", self.tiles())
+    }
+}
@@ -0,0 +1,84 @@
+use qwirkle_solver::{
+    board::{
+        analysis::{lines, unfillable_positions, Attribute},
+        direction::Direction,
+        position::Position,
+        Board,
+    },
+    game::snapshot,
+    notation::parse_tiles,
+    rules::ruleset::RuleSet,
+    tile::{Color, Shape},
+};
+
+fn board(locations: &str) -> Board {
+    snapshot::parse(&format!("board {locations}"))
+        .unwrap()
+        .board
+}
+
+#[test]
+pub fn lines_have_an_attribute_missing_tiles_and_ends() {
+    let rules = RuleSet::default();
+    let board = board("rS@0,0 rO@1,0 rD@2,0 bS@0,1");
+
+    let lines = lines(&rules, &board);
+    assert_eq!(lines.len(), 2, "{lines:?}");
+
+    let row = &lines[0];
+    assert_eq!(row.direction, Direction(1, 0));
+    assert_eq!(row.attribute, Attribute::Color(Color::Red));
+    assert_eq!(row.missing, parse_tiles("rCr*r+").unwrap());
+    assert_eq!(row.missing_left, row.missing);
+    let ends = row.ends.iter().map(|end| end.position).collect::<Vec<_>>();
+    assert_eq!(
+        ends,
+        vec![Position { x: -1, y: 0 }, Position { x: 3, y: 0 }]
+    );
+    assert!(row
+        .ends
+        .iter()
+        .all(|end| end.tiles == parse_tiles("rCr*r+").unwrap()));
+
+    let column = &lines[1];
+    assert_eq!(column.direction, Direction(0, 1));
+    assert_eq!(column.attribute, Attribute::Shape(Shape::Square));
+    assert_eq!(column.missing, parse_tiles("oSySgSpS").unwrap());
+    assert!(!row.is_qwirkle_threat(&rules) && !row.is_dead(&rules));
+}
+
+#[test]
+pub fn exhausted_tiles_kill_lines_and_threats() {
+    let rules = RuleSet::default();
+    let threat = board("rS@0,0 rO@1,0 rD@2,0 rC@3,0 r*@4,0");
+    let line = &lines(&rules, &threat)[0];
+    assert!(line.is_qwirkle_threat(&rules));
+    assert!(!line.is_dead(&rules));
+
+    // with a single copy of each tile, the only red cross is already placed
+    let rules = RuleSet {
+        copies: 1,
+        ..RuleSet::default()
+    };
+    let blocked = board("rS@0,0 rO@1,0 rD@2,0 rC@3,0 r*@4,0 r+@0,1");
+    let line = &lines(&rules, &blocked)[0];
+    assert_eq!(line.len(), 5);
+    assert!(line.missing_left.is_empty());
+    assert!(line.ends.iter().all(|end| !end.is_fillable()));
+    assert!(!line.is_qwirkle_threat(&rules));
+    assert!(line.is_dead(&rules));
+}
+
+#[test]
+pub fn ends_of_a_qwirkle_are_unfillable() {
+    let rules = RuleSet::default();
+    let board = board("rS@0,0 rO@1,0 rD@2,0 rC@3,0 r*@4,0 r+@5,0");
+
+    let line = &lines(&rules, &board)[0];
+    assert!(line.is_complete(&rules) && !line.is_dead(&rules));
+
+    let positions = unfillable_positions(&rules, &board);
+    assert_eq!(positions.len(), 2, "{positions:?}");
+    assert!(positions.contains(&Position { x: -1, y: 0 }));
+    assert!(positions.contains(&Position { x: 6, y: 0 }));
+}
@@ -0,0 +1,108 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use qwirkle_solver::{
+    bag::{Bag, BAG_SIZE},
+    rules::ruleset::RuleSet,
+    tile::{Color, Shape, Tile},
+    tile_set::TileSet,
+};
+
+#[test]
+pub fn new_bag_holds_three_copies_of_each_tile() {
+    let bag = Bag::new();
+
+    assert_eq!(bag.len(), BAG_SIZE);
+    for color in Color::values() {
+        for shape in Shape::values() {
+            assert_eq!(bag.count(Tile { color, shape }), 3);
+        }
+    }
+    assert_eq!(
+        bag.tiles().iter().copied().collect::<TileSet>(),
+        TileSet::full()
+    );
+}
+
+#[test]
+pub fn tile_set_counts_copies() {
+    let tile = Tile {
+        color: Color::Green,
+        shape: Shape::Club,
+    };
+    let mut set = TileSet::new();
+    assert!(set.is_empty());
+    assert!(!set.remove(tile));
+
+    set.extend([tile, tile]);
+    assert_eq!(set.count(tile), 2);
+    assert_eq!(set.len(), 2);
+    assert!(set.remove(tile));
+    assert_eq!(set.tiles(), vec![tile]);
+
+    assert_eq!(TileSet::full().len(), BAG_SIZE);
+}
+
+#[test]
+pub fn draw_removes_tiles_from_bag() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut bag = Bag::new();
+
+    let tiles = bag.draw(6, &mut rng);
+
+    assert_eq!(tiles.len(), 6);
+    assert_eq!(bag.len(), BAG_SIZE - 6);
+    for tile in &tiles {
+        let drawn = tiles.iter().filter(|&t| t == tile).count();
+        assert_eq!(bag.count(*tile) + drawn, 3);
+    }
+}
+
+#[test]
+pub fn draw_stops_when_bag_is_empty() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut bag = Bag::new();
+
+    let tiles = bag.draw(BAG_SIZE + 10, &mut rng);
+
+    assert_eq!(tiles.len(), BAG_SIZE);
+    assert!(bag.is_empty());
+    assert!(bag.draw(1, &mut rng).is_empty());
+}
+
+#[test]
+pub fn exchange_keeps_bag_size() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut bag = Bag::new();
+    let hand = bag.draw(3, &mut rng);
+
+    let drawn = bag.exchange(hand.clone(), &mut rng);
+
+    assert_eq!(drawn.len(), 3);
+    assert_eq!(bag.len(), BAG_SIZE - 3);
+    for tile in &hand {
+        assert!(bag.count(*tile) >= 1);
+    }
+}
+
+#[test]
+pub fn variant_bag_holds_fewer_tiles() {
+    let rules = RuleSet::square(4);
+    let bag = Bag::with_rules(&rules);
+
+    assert_eq!(bag.len(), 48);
+    assert_eq!(bag.len(), rules.bag_size());
+    assert_eq!(
+        bag.count(Tile {
+            color: Color::Green,
+            shape: Shape::Club
+        }),
+        3
+    );
+    assert_eq!(
+        bag.count(Tile {
+            color: Color::Blue,
+            shape: Shape::Club
+        }),
+        0
+    );
+}
@@ -0,0 +1,237 @@
+use qwirkle_solver::board::{
+    direction::Direction,
+    error::BoardError,
+    location::Location,
+    placement::Placement,
+    position::{Coordinate, Position},
+    r#move::PartialMove,
+    Board,
+};
+use qwirkle_solver::rules::{
+    error::MoveError, ruleset::RuleSet, validate_partial_move, validate_placement,
+};
+use qwirkle_solver::tile::{Color, Shape, Tile};
+
+fn location(x: Coordinate, y: Coordinate, color: Color, shape: Shape) -> Location {
+    Location {
+        position: Position { x, y },
+        tile: Tile { color, shape },
+    }
+}
+
+#[test]
+pub fn add_tile_refuses_occupied_position() {
+    let mut board = Board::new();
+    board
+        .add_tile(location(0, 0, Color::Red, Shape::Star))
+        .unwrap();
+
+    let error = board.add_tile(location(0, 0, Color::Blue, Shape::Star));
+
+    assert_eq!(error, Err(BoardError::Occupied(Position { x: 0, y: 0 })));
+    assert_eq!(board.tiles().len(), 1);
+    assert_eq!(
+        board.get(0, 0),
+        Some(Tile {
+            color: Color::Red,
+            shape: Shape::Star
+        })
+    );
+}
+
+#[test]
+pub fn add_tile_refuses_out_of_range_position() {
+    let mut board = Board::new();
+
+    let error = board.add_tile(location(Coordinate::MAX, 0, Color::Red, Shape::Star));
+
+    assert_eq!(
+        error,
+        Err(BoardError::OutOfRange(Position {
+            x: Coordinate::MAX,
+            y: 0
+        }))
+    );
+    assert!(board.tiles().is_empty());
+}
+
+#[test]
+pub fn add_tiles_is_atomic() {
+    let mut board = Board::new();
+
+    // the second tile would be out of range
+    let partial_move = PartialMove {
+        combination: vec![
+            Tile {
+                color: Color::Red,
+                shape: Shape::Star,
+            },
+            Tile {
+                color: Color::Red,
+                shape: Shape::Cross,
+            },
+        ],
+        position: Position {
+            x: Position::MAX,
+            y: 0,
+        },
+        direction: Direction(1, 0),
+    };
+    let error = board.add_tiles(&partial_move);
+
+    assert_eq!(
+        error,
+        Err(BoardError::OutOfRange(Position {
+            x: Coordinate::MAX,
+            y: 0
+        }))
+    );
+    assert!(board.tiles().is_empty());
+
+    // the last tile would be on an already taken position
+    board
+        .add_tile(location(2, 0, Color::Red, Shape::Circle))
+        .unwrap();
+    let placement = Placement {
+        locations: vec![
+            location(0, 0, Color::Red, Shape::Star),
+            location(1, 0, Color::Red, Shape::Cross),
+            location(2, 0, Color::Red, Shape::Square),
+        ],
+    };
+    let error = board.add_placement(&placement);
+
+    assert_eq!(error, Err(BoardError::Occupied(Position { x: 2, y: 0 })));
+    assert_eq!(board.tiles().len(), 1);
+    assert!(board.get(0, 0).is_none());
+}
+
+#[test]
+pub fn step_is_checked_at_the_extremes() {
+    let top = Position {
+        x: 0,
+        y: Coordinate::MAX,
+    };
+    assert_eq!(top.step(Direction(0, 1), 1), None);
+    assert_eq!(
+        top.step(Direction(0, -1), 3),
+        Some(Position {
+            x: 0,
+            y: Coordinate::MAX - 3
+        })
+    );
+
+    let left = Position {
+        x: Coordinate::MIN,
+        y: 0,
+    };
+    assert_eq!(left.step(Direction(-1, 0), 1), None);
+    assert_eq!(
+        left.step(Direction(1, 0), Coordinate::MAX),
+        Some(Position { x: -1, y: 0 })
+    );
+    assert_eq!(left.step(Direction(1, 0), Coordinate::MIN), None);
+}
+
+#[test]
+pub fn lines_at_the_extremes_are_validated_without_overflow() {
+    let mut board = Board::new();
+    board
+        .add_tile(location(Position::MAX, 0, Color::Red, Shape::Star))
+        .unwrap();
+    board
+        .add_tile(location(Position::MAX - 1, 0, Color::Red, Shape::Cross))
+        .unwrap();
+
+    // looking beyond the last tile stops at the edge
+    let tiles = board.get_tiles(
+        Position {
+            x: Position::MAX - 2,
+            y: 0,
+        },
+        Direction(1, 0),
+    );
+    assert_eq!(tiles.len(), 2);
+
+    // extending the line beyond the edge is refused
+    let partial_move = PartialMove {
+        combination: vec![
+            Tile {
+                color: Color::Red,
+                shape: Shape::Square,
+            },
+            Tile {
+                color: Color::Red,
+                shape: Shape::Circle,
+            },
+        ],
+        position: Position {
+            x: Position::MAX - 2,
+            y: 0,
+        },
+        direction: Direction(1, 0),
+    };
+    let error = validate_partial_move(&RuleSet::default(), &board, partial_move.clone());
+    assert_eq!(
+        error.map(|_| ()),
+        Err(MoveError::OutOfRange(vec![Position {
+            x: Coordinate::MAX,
+            y: 0
+        }]))
+    );
+    assert!(board.add_tiles(&partial_move).is_err());
+    assert_eq!(board.tiles().len(), 2);
+}
+
+#[test]
+pub fn far_apart_placements_are_validated_without_overflow() {
+    let mut board = Board::new();
+    board
+        .add_tile(location(0, 0, Color::Red, Shape::Star))
+        .unwrap();
+
+    let placement = Placement {
+        locations: vec![
+            location(-30000, 0, Color::Red, Shape::Square),
+            location(30000, 0, Color::Red, Shape::Circle),
+        ],
+    };
+    let error = validate_placement(&RuleSet::default(), &board, &placement);
+    assert_eq!(
+        error,
+        Err(MoveError::LineTooLong(vec![
+            Position { x: -30000, y: 0 },
+            Position { x: 30000, y: 0 }
+        ]))
+    );
+}
+
+#[test]
+pub fn undo_takes_back_an_applied_move() {
+    let mut board = Board::new();
+    board
+        .add_tile(location(0, 0, Color::Red, Shape::Circle))
+        .unwrap();
+
+    let first = board.apply(&"bSbC@0,1>+2".parse().unwrap()).unwrap();
+    let second = board.apply(&"rSrC@-1,0<+3".parse().unwrap()).unwrap();
+    assert_eq!(board.tiles().len(), 5);
+
+    board.undo(second);
+    assert_eq!(board.tiles().len(), 3);
+    assert!(board.get(-1, 0).is_none());
+    assert!(board.get(-2, 0).is_none());
+
+    board.undo(first);
+    assert_eq!(
+        board.tiles(),
+        &vec![location(0, 0, Color::Red, Shape::Circle)]
+    );
+    assert!(board.get(0, 1).is_none());
+    assert!(board.get(1, 1).is_none());
+
+    // positions are free again
+    let token = board.apply(&"bSbC@0,1>+2".parse().unwrap()).unwrap();
+    assert_eq!(board.tiles().len(), 3);
+    board.undo(token);
+}
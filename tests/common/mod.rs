@@ -2,17 +2,7 @@ use std::fs::File;
 use std::io::prelude::*;
 use std::path::Path;
 
-use qwirkle_solver::{
-    board::{location::Location, Board},
-    player::Player,
-    tile::Tiles,
-};
-
-pub fn setup_board(board: &mut Board, tiles: Vec<Location>) {
-    for location in tiles {
-        board.add_tile(location);
-    }
-}
+use qwirkle_solver::{board::Board, player::Player, strategy::greedy::GreedyStrategy, tile::Tiles};
 
 pub fn create_player(hand: Tiles) -> Player {
     let mut player = Player {
@@ -20,6 +10,7 @@ pub fn create_player(hand: Tiles) -> Player {
         points: 0,
         hand,
         combinations: Vec::new(),
+        strategy: Box::new(GreedyStrategy),
     };
     player.update_combinations();
 
@@ -35,7 +26,7 @@ pub fn save(filename: &str, text: String) {
     let path = Path::new(&full_path);
     let display = path.display();
 
-    let mut file = match File::create(&path) {
+    let mut file = match File::create(path) {
         Err(why) => panic!("couldn't create {}: {}", display, why),
         Ok(file) => file,
     };
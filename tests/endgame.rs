@@ -0,0 +1,177 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use qwirkle_solver::{
+    game::{snapshot, Game},
+    notation::parse_tiles,
+    player::{action::Action, Points},
+    rules::ruleset::RuleSet,
+    strategy::{
+        endgame::{EndgameSolver, EndgameStrategy, Solution},
+        greedy::GreedyStrategy,
+        Strategy,
+    },
+};
+
+/// Plays a game of a small variant with `GreedyStrategy` until the bag is empty.
+fn endgame(seed: u64) -> Game {
+    let strategies = vec![
+        Box::new(GreedyStrategy) as Box<dyn Strategy>,
+        Box::new(GreedyStrategy),
+    ];
+    let mut game = Game::with_rules(RuleSet::square(4), strategies, seed);
+    while !game.bag().is_empty() && game.step().is_some() {}
+    assert!(!game.is_over());
+
+    game
+}
+
+/// Resumes `game` with `strategies` and returns points the player to move earns
+/// until the end of the game, minus points its opponent earns.
+fn margin(game: &Game, strategies: Vec<Box<dyn Strategy>>) -> Points {
+    let mut resumed = Game::from_snapshot(game.snapshot(), *game.rules(), strategies, 0);
+    while resumed.step().is_some() {}
+
+    gained(game, &resumed)
+}
+
+/// Returns points the player to move in `game` earned until `resumed`,
+/// minus points its opponent earned.
+fn gained(game: &Game, resumed: &Game) -> Points {
+    let current = game.current_player().id;
+    let difference = |game: &Game| {
+        game.scores()
+            .iter()
+            .map(|&(id, points)| if id == current { points } else { -points })
+            .sum::<Points>()
+    };
+
+    difference(resumed) - difference(game)
+}
+
+/// Solves the endgame of `game` from the point of view of the player to move.
+fn solve(game: &Game) -> Option<Solution> {
+    let current = game.current_player();
+    let opponent = game
+        .players()
+        .iter()
+        .find(|player| player.id != current.id)
+        .unwrap();
+
+    EndgameSolver::default().solve(
+        game.rules(),
+        game.board(),
+        [current.hand.clone(), opponent.hand.clone()],
+    )
+}
+
+#[test]
+pub fn solver_counts_the_end_bonus() {
+    let rules = RuleSet::default();
+    let board = snapshot::parse("board rS@0,0 oS@1,0 yS@2,0").unwrap().board;
+    let hands = [parse_tiles("gS").unwrap(), parse_tiles("bCpO").unwrap()];
+
+    let solution = EndgameSolver::default()
+        .solve(&rules, &board, hands)
+        .unwrap();
+
+    assert_eq!(solution.value, 4 + rules.end_bonus);
+    let [Action::Place(r#move)] = &solution.actions[..] else {
+        panic!("the game ends with the first move: {:?}", solution.actions);
+    };
+    assert_eq!(r#move.combination, parse_tiles("gS").unwrap());
+}
+
+#[test]
+pub fn solver_passes_when_no_tile_can_be_placed() {
+    let rules = RuleSet::default();
+    let board = snapshot::parse("board rS@0,0 oS@1,0").unwrap().board;
+    // the diamond can't be placed next to squares of other colors
+    let hands = [parse_tiles("bD").unwrap(), parse_tiles("yS").unwrap()];
+
+    let solution = EndgameSolver::default()
+        .solve(&rules, &board, hands)
+        .unwrap();
+
+    assert_eq!(solution.value, -(3 + rules.end_bonus));
+    assert_eq!(solution.actions.len(), 2);
+    assert_eq!(solution.actions[0], Action::Pass);
+}
+
+#[test]
+pub fn optimal_play_reaches_the_solved_value() {
+    for seed in 0..3 {
+        let game = endgame(seed);
+        let solution = solve(&game).unwrap();
+
+        let optimal = margin(
+            &game,
+            vec![
+                Box::new(EndgameStrategy::default()),
+                Box::new(EndgameStrategy::default()),
+            ],
+        );
+        assert_eq!(optimal, solution.value, "seed {seed}");
+
+        // whoever plays first, the opponent can't do better than optimal
+        let strategies: Vec<Box<dyn Strategy>> = if game.current_player().id == 1 {
+            vec![
+                Box::new(EndgameStrategy::default()),
+                Box::new(GreedyStrategy),
+            ]
+        } else {
+            vec![
+                Box::new(GreedyStrategy),
+                Box::new(EndgameStrategy::default()),
+            ]
+        };
+        assert!(margin(&game, strategies) >= solution.value, "seed {seed}");
+    }
+}
+
+#[test]
+pub fn solution_actions_reach_the_solved_value() {
+    for seed in 0..3 {
+        let game = endgame(seed);
+        let solution = solve(&game).unwrap();
+
+        let strategies = vec![
+            Box::new(GreedyStrategy) as Box<dyn Strategy>,
+            Box::new(GreedyStrategy),
+        ];
+        let mut resumed = Game::from_snapshot(game.snapshot(), *game.rules(), strategies, 0);
+        for action in solution.actions {
+            resumed.play_action(action).unwrap();
+        }
+
+        assert!(resumed.is_over(), "seed {seed}");
+        assert_eq!(gained(&game, &resumed), solution.value, "seed {seed}");
+    }
+}
+
+#[test]
+pub fn strategy_falls_back_when_the_solver_gives_up() {
+    let game = endgame(1);
+    let limited = EndgameSolver {
+        max_nodes: 1,
+        ..EndgameSolver::default()
+    };
+
+    let current = game.current_player();
+    game.with_view(current.id, |view| {
+        let opponent = view.unseen_tiles().tiles();
+        assert!(limited
+            .solve(game.rules(), game.board(), [current.hand.clone(), opponent])
+            .is_none());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut strategy = EndgameStrategy {
+            solver: limited,
+            fallback: Box::new(GreedyStrategy),
+        };
+        assert_eq!(
+            strategy.choose(view, &mut rng),
+            GreedyStrategy.choose(view, &mut rng)
+        );
+    })
+    .unwrap();
+}
@@ -0,0 +1,319 @@
+use rand::rngs::StdRng;
+
+use qwirkle_solver::{
+    board::{direction::Direction, position::Position, r#move::Move},
+    game::{opening_player, outcome::EndReason, record, snapshot, Game},
+    player::action::Action,
+    rules::{error::MoveError, ruleset::RuleSet},
+    strategy::{greedy::GreedyStrategy, Strategy, View},
+    tile_set::TileSet,
+};
+
+/// Never plays anything, but checks what it is shown.
+struct PassStrategy;
+
+impl Strategy for PassStrategy {
+    fn choose(&mut self, view: &View, _rng: &mut StdRng) -> Action {
+        assert_eq!(view.scores.len(), 2);
+        assert!(view.scores.iter().any(|&(id, _)| id == view.player_id));
+
+        Action::Pass
+    }
+}
+
+/// Always tries to exchange no tile, which is illegal.
+struct EmptyExchangeStrategy;
+
+impl Strategy for EmptyExchangeStrategy {
+    fn choose(&mut self, _view: &View, _rng: &mut StdRng) -> Action {
+        Action::Exchange(Vec::new())
+    }
+}
+
+#[test]
+pub fn longest_combination_opens_the_game() {
+    for seed in 0..10 {
+        let mut game = Game::with_seed(4, seed);
+        let opening_length = |index: usize| {
+            game.players()[index]
+                .best_opening()
+                .map_or(0, |opening| opening.len())
+        };
+
+        let first = opening_player(game.players());
+        let first_length = opening_length(first);
+        assert_eq!(game.current_player().id, game.players()[first].id);
+        assert!((0..4).all(|index| opening_length(index) <= first_length));
+        assert!((0..first).all(|index| opening_length(index) < first_length));
+
+        // the opening move places the whole combination in the center
+        let result = game.step().unwrap();
+        let Action::Place(r#move) = result.action else {
+            panic!("opening player should place tiles");
+        };
+        assert_eq!(r#move.combination.len(), first_length);
+        assert_eq!(game.board().tiles().len(), first_length);
+        assert!(game.board().get(0, 0).is_some());
+    }
+}
+
+#[test]
+pub fn game_plays_until_over() {
+    let mut game = Game::with_seed(2, 0);
+
+    let mut results = Vec::new();
+    while let Some(result) = game.step() {
+        results.push(result);
+    }
+
+    assert!(game.is_over());
+    assert!(game.step().is_none());
+    assert!(game.winner().is_some());
+
+    // every emitted score matches the sum of points earned by the player
+    for player in game.players() {
+        let total = results
+            .iter()
+            .filter(|result| result.player_id == player.id)
+            .map(|result| result.points)
+            .sum::<i32>();
+        assert_eq!(total, player.points);
+    }
+
+    // placed tiles are exactly the tiles on the board
+    let placed = results
+        .iter()
+        .map(|result| match &result.action {
+            Action::Place(r#move) => r#move.combination.len(),
+            Action::Exchange(_) | Action::Pass => 0,
+        })
+        .sum::<usize>();
+    assert_eq!(placed, game.board().tiles().len());
+}
+
+#[test]
+pub fn tiles_are_conserved_every_turn() {
+    for seed in 0..3 {
+        let mut game = Game::with_seed(3, seed);
+        assert_eq!(game.tile_set(), TileSet::full());
+
+        while game.step().is_some() {
+            assert_eq!(game.tile_set(), TileSet::full());
+        }
+    }
+}
+
+#[test]
+pub fn variant_game_plays_until_over() {
+    let rules = RuleSet::square(4);
+    let strategies = (0..2)
+        .map(|_| Box::new(GreedyStrategy) as Box<dyn Strategy>)
+        .collect();
+    let mut game = Game::with_rules(rules, strategies, 0);
+    assert!(game.players().iter().all(|player| player.hand.len() == 4));
+
+    while game.step().is_some() {
+        assert_eq!(game.tile_set(), rules.tile_set());
+    }
+
+    assert!(game.is_over());
+    assert!(game
+        .board()
+        .tiles()
+        .iter()
+        .all(|location| location.tile.color.index() < 4 && location.tile.shape.index() < 4));
+}
+
+#[test]
+pub fn game_ends_with_outcome() {
+    for seed in 0..5 {
+        let mut game = Game::with_seed(4, seed);
+        while game.step().is_some() {}
+
+        let outcome = game.outcome().expect("game should be over");
+        match outcome.reason {
+            EndReason::HandEmptied(id) => {
+                assert!(game.bag().is_empty());
+
+                let player = game.players().iter().find(|p| p.id == id).unwrap();
+                assert!(player.hand.is_empty());
+            }
+            EndReason::Blocked => {
+                assert!(game.is_blocked());
+            }
+        }
+
+        let scores = game
+            .players()
+            .iter()
+            .map(|player| (player.id, player.points))
+            .collect::<Vec<_>>();
+        assert_eq!(outcome.scores, scores);
+        assert_eq!(game.winner().map(|player| player.id), outcome.winner());
+    }
+}
+
+#[test]
+pub fn play_turn_lets_every_player_play() {
+    let mut game = Game::with_seed(3, 0);
+    let first_id = game.current_player().id;
+
+    let results = game.play_turn();
+
+    let mut ids = results
+        .iter()
+        .map(|result| result.player_id)
+        .collect::<Vec<u8>>();
+    assert_eq!(ids[0], first_id);
+    ids.sort();
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert!(results.iter().all(|result| result.turn == 1));
+    assert_eq!(game.turn(), 2);
+}
+
+#[test]
+pub fn strategies_play_against_each_other() {
+    let mut game = Game::with_strategies(vec![Box::new(PassStrategy), Box::new(GreedyStrategy)], 0);
+    while game.step().is_some() {}
+
+    let scores = game.scores();
+    assert_eq!(scores[0], (1, 0));
+    assert!(scores[1].1 > 0);
+    assert_eq!(game.winner().map(|player| player.id), Some(2));
+}
+
+#[test]
+pub fn illegal_action_is_refused() {
+    let mut game = Game::with_seed(2, 0);
+    let player_id = game.current_player().id;
+    let tile = game.current_player().hand[0];
+
+    let r#move = Move {
+        combination: vec![tile],
+        position: Position { x: 3, y: 3 },
+        direction: Direction(1, 0),
+        points: 1,
+    };
+    let error = game.play_action(Action::Place(r#move));
+
+    assert_eq!(
+        error.map(|_| ()),
+        Err(MoveError::FirstMoveNotAtOrigin(Position { x: 3, y: 3 }))
+    );
+    assert_eq!(game.current_player().id, player_id);
+    assert!(game.board().tiles().is_empty());
+}
+
+#[test]
+pub fn illegal_choice_is_returned_with_the_pass() {
+    let strategies = vec![
+        Box::new(EmptyExchangeStrategy) as Box<dyn Strategy>,
+        Box::new(EmptyExchangeStrategy),
+    ];
+    let mut game = Game::with_strategies(strategies, 0);
+
+    let result = game.step().unwrap();
+
+    assert_eq!(result.action, Action::Pass);
+    assert_eq!(
+        result.refused,
+        Some((Action::Exchange(Vec::new()), MoveError::Empty))
+    );
+    // records keep the pass alone
+    assert_eq!(game.record().unwrap().turns[0].refused, None);
+}
+
+#[test]
+pub fn seed_reproduces_a_game() {
+    let play = |seed: u64| {
+        let mut game = Game::with_seed(3, seed);
+        let hands = game
+            .players()
+            .iter()
+            .map(|player| player.hand.clone())
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::new();
+        while let Some(result) = game.step() {
+            results.push(result);
+        }
+
+        (hands, results, game.outcome().cloned())
+    };
+
+    assert!(play(42) == play(42));
+    assert!(play(42) != play(43));
+}
+
+#[test]
+pub fn undo_puts_the_game_back_and_redo_replays_it() {
+    let mut game = Game::with_seed(3, 42);
+    let initial = snapshot::serialize(&game.snapshot());
+    assert!(game.undo().is_none());
+
+    let results = (0..12).filter_map(|_| game.step()).collect::<Vec<_>>();
+    let last = snapshot::serialize(&game.snapshot());
+
+    for result in results.iter().rev() {
+        assert_eq!(game.undo().as_ref(), Some(result));
+    }
+    assert_eq!(snapshot::serialize(&game.snapshot()), initial);
+    assert_eq!(game.turn(), 1);
+    assert!(game.record().unwrap().turns.is_empty());
+    assert!(game.undo().is_none());
+
+    for result in results.iter() {
+        assert_eq!(game.redo().as_ref(), Some(result));
+    }
+    assert_eq!(snapshot::serialize(&game.snapshot()), last);
+    assert_eq!(game.record().unwrap().turns, results);
+    assert!(game.redo().is_none());
+}
+
+#[test]
+pub fn playing_after_undo_forgets_redo() {
+    let mut game = Game::with_seed(2, 7);
+    game.step().unwrap();
+    game.step().unwrap();
+
+    game.undo().unwrap();
+    game.play_action(Action::Pass).unwrap();
+
+    assert!(game.redo().is_none());
+    assert_eq!(game.record().unwrap().turns.len(), 2);
+}
+
+#[test]
+pub fn undo_and_redo_keep_the_record_replayable() {
+    let mut game = Game::with_seed(2, 7);
+    for _ in 0..6 {
+        game.step();
+    }
+
+    for _ in 0..3 {
+        game.undo().unwrap();
+    }
+    game.redo().unwrap();
+    game.undo().unwrap();
+    for _ in 0..3 {
+        game.redo().unwrap();
+    }
+
+    // playing another action after an undo draws tiles as a replay would
+    game.undo().unwrap();
+    game.undo().unwrap();
+    game.play_action(Action::Pass).unwrap();
+    while game.step().is_some() {}
+
+    let replayed = record::replay(game.record().unwrap()).unwrap();
+    assert_eq!(
+        snapshot::serialize(&replayed.snapshot()),
+        snapshot::serialize(&game.snapshot())
+    );
+}
+
+#[test]
+#[should_panic(expected = "a game needs at least one player")]
+pub fn game_needs_players() {
+    Game::with_seed(0, 0);
+}
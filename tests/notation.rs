@@ -0,0 +1,108 @@
+use qwirkle_solver::{
+    board::{
+        direction::Direction,
+        position::Position,
+        r#move::{Move, PartialMove},
+    },
+    notation::{format_tiles, parse_tiles, NotationError},
+    tile::{Color, Shape, Tile},
+};
+
+#[test]
+pub fn tiles_round_trip() {
+    for color in Color::values() {
+        for shape in Shape::values() {
+            let tile = Tile { color, shape };
+            let text = tile.to_string();
+
+            assert_eq!(text.chars().count(), 2);
+            assert_eq!(text.parse::<Tile>(), Ok(tile));
+        }
+    }
+
+    let tile = Tile {
+        color: Color::Red,
+        shape: Shape::Square,
+    };
+    assert_eq!(tile.to_string(), "rS");
+}
+
+#[test]
+pub fn positions_and_directions_round_trip() {
+    for (x, y) in [(0, 0), (3, -2), (Position::MIN, Position::MAX)] {
+        let position = Position { x, y };
+        assert_eq!(position.to_string().parse::<Position>(), Ok(position));
+    }
+    assert_eq!(Position { x: 3, y: -2 }.to_string(), "3,-2");
+
+    for direction in Direction::values() {
+        assert_eq!(direction.to_string().parse::<Direction>(), Ok(direction));
+    }
+    assert_eq!(Direction(1, 0).to_string(), ">");
+}
+
+#[test]
+pub fn moves_round_trip() {
+    let partial_move = PartialMove {
+        combination: vec![
+            Tile {
+                color: Color::Blue,
+                shape: Shape::Circle,
+            },
+            Tile {
+                color: Color::Blue,
+                shape: Shape::Cross,
+            },
+        ],
+        position: Position { x: 3, y: -2 },
+        direction: Direction(0, -1),
+    };
+    assert_eq!(partial_move.to_string(), "bOb+@3,-2v");
+    assert_eq!(
+        "bOb+@3,-2v".parse::<PartialMove>(),
+        Ok(partial_move.clone())
+    );
+
+    let r#move = partial_move.into_move(4);
+    assert_eq!(r#move.to_string(), "bOb+@3,-2v+4");
+    assert_eq!("bOb+@3,-2v+4".parse::<Move>(), Ok(r#move));
+}
+
+#[test]
+pub fn combinations_round_trip() {
+    let tiles = parse_tiles("rSo*p+").unwrap();
+
+    assert_eq!(tiles.len(), 3);
+    assert_eq!(format_tiles(&tiles), "rSo*p+");
+    assert_eq!(parse_tiles(""), Ok(vec![]));
+}
+
+#[test]
+pub fn invalid_notation_is_refused() {
+    assert_eq!(
+        "xS".parse::<Tile>(),
+        Err(NotationError::Color("x".to_owned()))
+    );
+    assert_eq!(
+        "rs".parse::<Tile>(),
+        Err(NotationError::Shape("s".to_owned()))
+    );
+    assert_eq!(
+        "rSS".parse::<Tile>(),
+        Err(NotationError::Tile("rSS".to_owned()))
+    );
+    assert_eq!(
+        "3;2".parse::<Position>(),
+        Err(NotationError::Position("3;2".to_owned()))
+    );
+    assert_eq!(
+        "N".parse::<Direction>(),
+        Err(NotationError::Direction("N".to_owned()))
+    );
+    assert_eq!(
+        "rS@0,0>".parse::<Move>(),
+        Err(NotationError::Move("rS@0,0>".to_owned()))
+    );
+    assert!("rS0,0>".parse::<PartialMove>().is_err());
+    assert!(parse_tiles("rSo").is_err());
+}
@@ -0,0 +1,164 @@
+use rand::rngs::StdRng;
+
+use qwirkle_solver::{
+    game::{
+        record::{parse, replay, replay_to, serialize, RecordError, ReplayError},
+        Game,
+    },
+    player::action::Action,
+    rules::{error::RuleSetError, ruleset::RuleSet},
+    strategy::{greedy::GreedyStrategy, Strategy, View},
+};
+
+/// Plays like `GreedyStrategy`, but draws random numbers before choosing.
+struct NoisyStrategy;
+
+impl Strategy for NoisyStrategy {
+    fn choose(&mut self, view: &View, rng: &mut StdRng) -> Action {
+        let _: u64 = rand::Rng::random(rng);
+        GreedyStrategy.choose(view, rng)
+    }
+}
+
+#[test]
+pub fn record_round_trips() {
+    let mut game = Game::with_seed(3, 0);
+    while game.step().is_some() {}
+
+    let record = game.record().unwrap();
+    let text = serialize(record);
+
+    assert_eq!(parse(&text).as_ref(), Ok(record));
+    assert_eq!(serialize(&parse(&text).unwrap()), text);
+}
+
+#[test]
+pub fn record_with_negative_bonuses_round_trips() {
+    let rules = RuleSet {
+        qwirkle_bonus: -3,
+        end_bonus: -6,
+        ..RuleSet::default()
+    };
+    let strategies = vec![
+        Box::new(GreedyStrategy) as Box<dyn Strategy>,
+        Box::new(GreedyStrategy),
+    ];
+    let mut game = Game::with_rules(rules, strategies, 5);
+    for _ in 0..4 {
+        game.step();
+    }
+
+    let record = game.record().unwrap();
+    let text = serialize(record);
+
+    assert!(text.starts_with("rules 6 6 3 6 6 -3 -6"));
+    assert_eq!(parse(&text).as_ref(), Ok(record));
+}
+
+#[test]
+pub fn replay_reproduces_the_game() {
+    let strategies = vec![
+        Box::new(NoisyStrategy) as Box<dyn Strategy>,
+        Box::new(GreedyStrategy),
+    ];
+    let mut game = Game::with_rules(RuleSet::default(), strategies, 7);
+    while game.step().is_some() {}
+    let record = game.record().unwrap();
+
+    // draws don't depend on random numbers used by strategies
+    let replayed = replay(record).unwrap();
+    assert_eq!(replayed.scores(), game.scores());
+    assert_eq!(replayed.board().tiles(), game.board().tiles());
+    assert_eq!(replayed.outcome(), game.outcome());
+
+    // any moment can be reproduced
+    let middle = replay_to(record, 5).unwrap();
+    assert_eq!(middle.record().unwrap().turns[..], record.turns[..5]);
+}
+
+#[test]
+pub fn replay_verifies_scores() {
+    let mut game = Game::with_seed(2, 0);
+    for _ in 0..4 {
+        game.step();
+    }
+    let mut record = game.record().unwrap().clone();
+    record.turns[2].score += 1;
+
+    assert_eq!(replay(&record).map(|_| ()), Err(ReplayError::Mismatch(2)));
+
+    record.turns.swap(0, 1);
+    assert_eq!(
+        replay(&record).map(|_| ()),
+        Err(ReplayError::WrongPlayer(0))
+    );
+
+    record.hands.swap(0, 1);
+    assert_eq!(replay(&record).map(|_| ()), Err(ReplayError::Hands));
+
+    record.hands.clear();
+    assert_eq!(replay(&record).map(|_| ()), Err(ReplayError::NoPlayers));
+
+    record.rules.hand_size = 0;
+    assert_eq!(
+        replay(&record).map(|_| ()),
+        Err(ReplayError::Rules(RuleSetError::EmptyHand))
+    );
+}
+
+#[test]
+pub fn invalid_record_is_refused() {
+    assert_eq!(
+        parse("seed 1\nseed 2").map(|_| ()),
+        Err(RecordError::Syntax(2))
+    );
+    assert_eq!(
+        parse("rules 6 6 3").map(|_| ()),
+        Err(RecordError::Syntax(1))
+    );
+    assert_eq!(
+        parse("turn 1 player 1 pass points 0 score 0").map(|_| ()),
+        Err(RecordError::Syntax(1))
+    );
+    assert!(matches!(
+        parse("turn 1 player 1 swap rS draw points 0 score 0"),
+        Err(RecordError::Notation(1, _))
+    ));
+    assert_eq!(
+        parse("seed 1\nrules 7 6 3 6 6 6 6").map(|_| ()),
+        Err(RecordError::Rules(2, RuleSetError::Colors(7)))
+    );
+    assert_eq!(
+        parse("rules 6 6 0 6 6 6 6").map(|_| ()),
+        Err(RecordError::Rules(1, RuleSetError::NoCopies))
+    );
+    assert_eq!(
+        parse("rules 6 6 3 0 6 6 6").map(|_| ()),
+        Err(RecordError::Rules(1, RuleSetError::EmptyHand))
+    );
+    assert_eq!(
+        parse("rules 6 6 3 6 1 6 6").map(|_| ()),
+        Err(RecordError::Rules(1, RuleSetError::QwirkleLength(1)))
+    );
+
+    assert_eq!(
+        parse("turn 1 player 1 pass draw points 0 score 0").map(|_| ()),
+        Err(RecordError::Missing("rules"))
+    );
+    assert_eq!(
+        parse("rules 6 6 3 6 6 6 6\nhand 1 rS").map(|_| ()),
+        Err(RecordError::Missing("seed"))
+    );
+    assert_eq!(
+        parse("rules 6 6 3 6 6 6 6\nseed 1").map(|_| ()),
+        Err(RecordError::Missing("hand"))
+    );
+
+    let record = parse(
+        "rules 6 6 3 6 6 6 6\nseed 1\nhand 2 rSbO\n\
+         turn 1 player 2 exchange rSbO draw yDp+ points 0 score 3",
+    )
+    .unwrap();
+    assert_eq!(record.turns[0].action.to_string(), "exchange rSbO");
+    assert_eq!(record.turns[0].drawn.len(), 2);
+}
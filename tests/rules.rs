@@ -0,0 +1,510 @@
+use qwirkle_solver::{
+    board::{
+        direction::Direction,
+        location::Location,
+        placement::Placement,
+        position::{Coordinate, Position},
+        r#move::PartialMove,
+        Board,
+    },
+    player::moves::get_combination_moves,
+    rules::{
+        error::{MoveError, RuleSetError},
+        ruleset::RuleSet,
+        validate_exchange, validate_hand_move, validate_partial_move, validate_placement,
+    },
+    tile::{Color, Shape, Tile, Tiles},
+};
+
+fn tile(color: Color, shape: Shape) -> Tile {
+    Tile { color, shape }
+}
+
+#[test]
+pub fn opening_qwirkle_scores_12() {
+    let board = Board::new();
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+        tile(Color::Red, Shape::Diamond),
+        tile(Color::Red, Shape::Club),
+        tile(Color::Red, Shape::Star),
+        tile(Color::Red, Shape::Cross),
+    ];
+
+    let partial_move = PartialMove {
+        combination,
+        position: Position { x: 0, y: 0 },
+        direction: Direction(1, 0),
+    };
+    let r#move = validate_partial_move(&RuleSet::default(), &board, partial_move).unwrap();
+
+    assert_eq!(r#move.points, 12);
+}
+
+#[test]
+pub fn opening_scores_its_length() {
+    let board = Board::new();
+    let combination = vec![
+        tile(Color::Red, Shape::Star),
+        tile(Color::Blue, Shape::Star),
+        tile(Color::Green, Shape::Star),
+    ];
+
+    let partial_move = PartialMove {
+        combination,
+        position: Position { x: 0, y: 0 },
+        direction: Direction(0, 1),
+    };
+    let r#move = validate_partial_move(&RuleSet::default(), &board, partial_move).unwrap();
+
+    assert_eq!(r#move.points, 3);
+}
+
+fn board_with(locations: Vec<(Coordinate, Coordinate, Tile)>) -> Board {
+    let mut board = Board::new();
+    for (x, y, tile) in locations {
+        board
+            .add_tile(Location {
+                position: Position { x, y },
+                tile,
+            })
+            .unwrap();
+    }
+    board
+}
+
+fn partial_move(
+    combination: Tiles,
+    x: Coordinate,
+    y: Coordinate,
+    direction: Direction,
+) -> PartialMove {
+    PartialMove {
+        combination,
+        position: Position { x, y },
+        direction,
+    }
+}
+
+fn placement(combination: Tiles, positions: Vec<(Coordinate, Coordinate)>) -> Placement {
+    let locations = combination
+        .into_iter()
+        .zip(positions)
+        .map(|(tile, (x, y))| Location {
+            position: Position { x, y },
+            tile,
+        })
+        .collect();
+    Placement { locations }
+}
+
+#[test]
+pub fn first_move_must_be_at_origin() {
+    let board = Board::new();
+    let combination = vec![tile(Color::Red, Shape::Star)];
+
+    let error = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination, 2, 1, Direction(1, 0)),
+    );
+
+    assert_eq!(
+        error,
+        Err(MoveError::FirstMoveNotAtOrigin(Position { x: 2, y: 1 }))
+    );
+}
+
+#[test]
+pub fn occupied_positions_are_reported() {
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (1, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+    ];
+
+    // a partial move must start on a free position, next tiles step over the board
+    let error = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination.clone(), 0, 0, Direction(1, 0)),
+    );
+    assert_eq!(
+        error,
+        Err(MoveError::Occupied(vec![Position { x: 0, y: 0 }]))
+    );
+
+    let error = validate_placement(
+        &RuleSet::default(),
+        &board,
+        &placement(combination, vec![(0, 0), (1, 0)]),
+    );
+    assert_eq!(
+        error,
+        Err(MoveError::Occupied(vec![
+            Position { x: 0, y: 0 },
+            Position { x: 1, y: 0 }
+        ]))
+    );
+}
+
+#[test]
+pub fn duplicate_tile_in_line_is_reported() {
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (1, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+    let combination = vec![tile(Color::Red, Shape::Star)];
+
+    let error = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination, 2, 0, Direction(1, 0)),
+    );
+
+    assert_eq!(
+        error,
+        Err(MoveError::DuplicateTile(vec![
+            Position { x: 0, y: 0 },
+            Position { x: 2, y: 0 }
+        ]))
+    );
+}
+
+#[test]
+pub fn mixed_attributes_are_reported() {
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (1, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+    let combination = vec![tile(Color::Blue, Shape::Cross)];
+
+    let error = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination, 2, 0, Direction(1, 0)),
+    );
+
+    assert_eq!(
+        error,
+        Err(MoveError::MixedAttributes(vec![
+            Position { x: 0, y: 0 },
+            Position { x: 2, y: 0 }
+        ]))
+    );
+}
+
+#[test]
+pub fn tiles_must_be_in_hand() {
+    let board = Board::new();
+    let hand = vec![
+        tile(Color::Red, Shape::Star),
+        tile(Color::Blue, Shape::Star),
+    ];
+    let combination = vec![tile(Color::Red, Shape::Star), tile(Color::Red, Shape::Star)];
+
+    let error = validate_hand_move(
+        &RuleSet::default(),
+        &board,
+        &hand,
+        partial_move(combination, 0, 0, Direction(1, 0)),
+    );
+
+    assert_eq!(
+        error,
+        Err(MoveError::NotInHand(vec![tile(Color::Red, Shape::Star)]))
+    );
+}
+
+#[test]
+pub fn move_must_touch_the_board() {
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (1, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+    let combination = vec![
+        tile(Color::Blue, Shape::Square),
+        tile(Color::Blue, Shape::Circle),
+    ];
+
+    let error = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination.clone(), 5, 5, Direction(1, 0)),
+    );
+    assert_eq!(
+        error,
+        Err(MoveError::NotConnected(vec![
+            Position { x: 5, y: 5 },
+            Position { x: 6, y: 5 }
+        ]))
+    );
+
+    // a diagonal neighbor isn't enough
+    let error = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination.clone(), 2, 1, Direction(1, 0)),
+    );
+    assert!(matches!(error, Err(MoveError::NotConnected(_))));
+}
+
+#[test]
+pub fn connected_move_is_valid() {
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (1, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+
+    // in the continuation of the line
+    let combination = vec![tile(Color::Red, Shape::Square)];
+    let r#move = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination, 2, 0, Direction(1, 0)),
+    );
+    assert_eq!(r#move.map(|r#move| r#move.points), Ok(3));
+
+    // perpendicular to the line
+    let combination = vec![
+        tile(Color::Blue, Shape::Star),
+        tile(Color::Green, Shape::Star),
+    ];
+    let r#move = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination, 0, 1, Direction(0, 1)),
+    );
+    assert_eq!(r#move.map(|r#move| r#move.points), Ok(3));
+}
+
+#[test]
+pub fn placement_must_be_aligned() {
+    let board = board_with(vec![(0, 0, tile(Color::Red, Shape::Star))]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+    ];
+
+    let error = validate_placement(
+        &RuleSet::default(),
+        &board,
+        &placement(combination, vec![(1, 0), (0, 1)]),
+    );
+
+    assert_eq!(
+        error,
+        Err(MoveError::NotAligned(vec![
+            Position { x: 1, y: 0 },
+            Position { x: 0, y: 1 }
+        ]))
+    );
+}
+
+#[test]
+pub fn placement_must_not_leave_gaps() {
+    let board = board_with(vec![(0, 0, tile(Color::Red, Shape::Star))]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+    ];
+
+    let error = validate_placement(
+        &RuleSet::default(),
+        &board,
+        &placement(combination, vec![(1, 0), (4, 0)]),
+    );
+
+    assert_eq!(
+        error,
+        Err(MoveError::Gap(vec![
+            Position { x: 2, y: 0 },
+            Position { x: 3, y: 0 }
+        ]))
+    );
+}
+
+#[test]
+pub fn placement_fills_gaps_and_both_sides() {
+    // red star _ red cross
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (2, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+        tile(Color::Red, Shape::Diamond),
+    ];
+
+    // square red star circle red cross diamond
+    let points = validate_placement(
+        &RuleSet::default(),
+        &board,
+        &placement(combination.clone(), vec![(-1, 0), (1, 0), (3, 0)]),
+    );
+    assert_eq!(points, Ok(5));
+
+    // the same placement, expressed as a partial move stepping over the board
+    let r#move = validate_partial_move(
+        &RuleSet::default(),
+        &board,
+        partial_move(combination, -1, 0, Direction(1, 0)),
+    );
+    assert_eq!(r#move.map(|r#move| r#move.points), Ok(5));
+}
+
+#[test]
+pub fn placement_converts_to_partial_move() {
+    let board = board_with(vec![(0, 0, tile(Color::Red, Shape::Star))]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+    ];
+    let placement = placement(combination, vec![(0, 1), (0, -1)]);
+
+    let partial_move = placement.clone().into_partial_move(&board).unwrap();
+
+    assert_eq!(partial_move.position, Position { x: 0, y: -1 });
+    assert_eq!(partial_move.direction, Direction(0, 1));
+    assert_eq!(partial_move.placement(&board).unwrap().positions().len(), 2);
+    assert!(partial_move
+        .placement(&board)
+        .unwrap()
+        .positions()
+        .iter()
+        .all(|position| placement.positions().contains(position)));
+}
+
+#[test]
+pub fn placement_with_gaps_does_not_convert_to_partial_move() {
+    let board = board_with(vec![(0, 0, tile(Color::Red, Shape::Star))]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+    ];
+
+    // free positions from 2,0 to 4,0 lie between both tiles
+    let placement = placement(combination, vec![(1, 0), (5, 0)]);
+
+    assert_eq!(placement.into_partial_move(&board), None);
+}
+
+#[test]
+pub fn moves_bridge_tiles_on_the_board() {
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Star)),
+        (2, 0, tile(Color::Red, Shape::Cross)),
+    ]);
+    let combination = vec![
+        tile(Color::Red, Shape::Square),
+        tile(Color::Red, Shape::Circle),
+    ];
+
+    let moves = get_combination_moves(&RuleSet::default(), &board, &combination).unwrap();
+
+    // filling the gap and extending the line scores the 4 tiles line
+    let bridging_move = moves.iter().find(|&r#move| {
+        let (partial_move, _) = r#move.clone().into_partial();
+        let positions = partial_move.placement(&board).unwrap().positions();
+        positions.contains(&Position { x: 1, y: 0 }) && positions.iter().all(|p| p.y == 0)
+    });
+    assert_eq!(bridging_move.map(|r#move| r#move.points), Some(4));
+}
+
+#[test]
+pub fn exchange_is_validated() {
+    let hand = vec![
+        tile(Color::Red, Shape::Star),
+        tile(Color::Blue, Shape::Star),
+    ];
+
+    assert_eq!(validate_exchange(&hand, 10, &vec![]), Err(MoveError::Empty));
+    assert_eq!(
+        validate_exchange(&hand, 10, &vec![tile(Color::Green, Shape::Star)]),
+        Err(MoveError::NotInHand(vec![tile(Color::Green, Shape::Star)]))
+    );
+    assert_eq!(
+        validate_exchange(&hand, 1, &hand),
+        Err(MoveError::BagTooSmall {
+            exchanged: 2,
+            available: 1
+        })
+    );
+    assert_eq!(validate_exchange(&hand, 2, &hand), Ok(()));
+}
+
+#[test]
+pub fn variant_rules_change_qwirkles() {
+    let rules = RuleSet::square(4);
+    let board = board_with(vec![
+        (0, 0, tile(Color::Red, Shape::Square)),
+        (1, 0, tile(Color::Red, Shape::Circle)),
+        (2, 0, tile(Color::Red, Shape::Diamond)),
+    ]);
+
+    // 4 tiles complete a line in a 4×4 variant
+    let r#move = validate_partial_move(
+        &rules,
+        &board,
+        partial_move(vec![tile(Color::Red, Shape::Club)], 3, 0, Direction(1, 0)),
+    );
+    assert_eq!(r#move.map(|r#move| r#move.points), Ok(8));
+
+    // but a 5th tile makes the line too long
+    let error = validate_partial_move(
+        &rules,
+        &board,
+        partial_move(
+            vec![tile(Color::Red, Shape::Club), tile(Color::Red, Shape::Star)],
+            3,
+            0,
+            Direction(1, 0),
+        ),
+    );
+    assert!(matches!(error, Err(MoveError::LineTooLong(_))));
+}
+
+#[test]
+pub fn rules_need_one_to_six_colors_and_shapes() {
+    assert_eq!(RuleSet::default().validate(), Ok(()));
+    assert_eq!(RuleSet::square(2).validate(), Ok(()));
+    assert_eq!(
+        RuleSet::square(1).validate(),
+        Err(RuleSetError::QwirkleLength(1))
+    );
+
+    let rules = RuleSet {
+        colors_number: 7,
+        ..RuleSet::default()
+    };
+    assert_eq!(rules.validate(), Err(RuleSetError::Colors(7)));
+
+    let rules = RuleSet {
+        shapes_number: 0,
+        ..RuleSet::default()
+    };
+    assert_eq!(rules.validate(), Err(RuleSetError::Shapes(0)));
+
+    let rules = RuleSet {
+        copies: 0,
+        ..RuleSet::default()
+    };
+    assert_eq!(rules.validate(), Err(RuleSetError::NoCopies));
+
+    let rules = RuleSet {
+        hand_size: 0,
+        ..RuleSet::default()
+    };
+    assert_eq!(rules.validate(), Err(RuleSetError::EmptyHand));
+
+    let rules = RuleSet {
+        qwirkle_length: 0,
+        ..RuleSet::default()
+    };
+    assert_eq!(rules.validate(), Err(RuleSetError::QwirkleLength(0)));
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+
+use qwirkle_solver::{
+    bag::Bag,
+    board::{direction::Direction, position::Position, r#move::Move, Board},
+    game::{snapshot::parse, Game},
+    player::{action::Action, Player},
+    rules::ruleset::RuleSet,
+    tile::{Color, Shape, Tile},
+    tile_set::TileSet,
+};
+
+#[test]
+pub fn json_shape_is_readable() {
+    let tile = Tile {
+        color: Color::Red,
+        shape: Shape::Square,
+    };
+    assert_eq!(
+        serde_json::to_value(tile).unwrap(),
+        json!({ "color": "red", "shape": "square" })
+    );
+
+    let r#move = Move {
+        combination: vec![tile],
+        position: Position { x: 3, y: -2 },
+        direction: Direction(1, 0),
+        points: 2,
+    };
+    assert_eq!(
+        serde_json::to_value(Action::Place(r#move)).unwrap(),
+        json!({
+            "place": {
+                "combination": [{ "color": "red", "shape": "square" }],
+                "position": { "x": 3, "y": -2 },
+                "direction": "east",
+                "points": 2
+            }
+        })
+    );
+    assert_eq!(serde_json::to_value(Action::Pass).unwrap(), json!("pass"));
+
+    let board = parse("board bO@0,0").unwrap().board;
+    assert_eq!(
+        serde_json::to_value(&board).unwrap(),
+        json!([{ "position": { "x": 0, "y": 0 }, "tile": { "color": "blue", "shape": "circle" } }])
+    );
+}
+
+#[test]
+pub fn types_round_trip() {
+    let mut game = Game::with_seed(2, 0);
+    for _ in 0..6 {
+        game.step();
+    }
+
+    let snapshot = game.snapshot();
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let parsed = serde_json::from_str::<qwirkle_solver::game::snapshot::Snapshot>(&json).unwrap();
+    assert_eq!(format!("{parsed:?}"), format!("{snapshot:?}"));
+
+    let record = game.record().unwrap();
+    let json = serde_json::to_string(record).unwrap();
+    assert_eq!(serde_json::from_str(&json).ok().as_ref(), Some(record));
+
+    let player = &game.players()[0];
+    let json = serde_json::to_string(player).unwrap();
+    let parsed = serde_json::from_str::<Player>(&json).unwrap();
+    assert_eq!(parsed.hand, player.hand);
+    assert_eq!(parsed.points, player.points);
+    assert!(!parsed.combinations.is_empty());
+
+    let json = serde_json::to_string(game.bag()).unwrap();
+    let parsed = serde_json::from_str::<Bag>(&json).unwrap();
+    assert_eq!(parsed.tiles(), game.bag().tiles());
+
+    let json = serde_json::to_string(&TileSet::full()).unwrap();
+    assert_eq!(
+        serde_json::from_str::<TileSet>(&json).ok(),
+        Some(TileSet::full())
+    );
+
+    let json = serde_json::to_string(&RuleSet::square(4)).unwrap();
+    assert_eq!(
+        serde_json::from_str::<RuleSet>(&json).ok(),
+        Some(RuleSet::square(4))
+    );
+}
+
+#[test]
+pub fn invalid_json_is_refused() {
+    let overlapping = json!([
+        { "position": { "x": 0, "y": 0 }, "tile": { "color": "blue", "shape": "circle" } },
+        { "position": { "x": 0, "y": 0 }, "tile": { "color": "red", "shape": "circle" } }
+    ]);
+    assert!(serde_json::from_value::<Board>(overlapping).is_err());
+    assert!(serde_json::from_value::<Direction>(json!("up")).is_err());
+    assert!(serde_json::from_value::<Tile>(json!({ "color": "pink", "shape": "star" })).is_err());
+
+    for field in ["shapes_number", "copies", "hand_size", "qwirkle_length"] {
+        let mut rules = serde_json::to_value(RuleSet::default()).unwrap();
+        rules[field] = json!(0);
+        assert!(serde_json::from_value::<RuleSet>(rules).is_err(), "{field}");
+    }
+}
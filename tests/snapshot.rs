@@ -0,0 +1,105 @@
+use qwirkle_solver::{
+    game::{
+        snapshot::{parse, serialize, SnapshotError},
+        Game,
+    },
+    notation::NotationError,
+    rules::ruleset::RuleSet,
+    strategy::{greedy::GreedyStrategy, Strategy},
+    tile_set::TileSet,
+};
+
+#[test]
+pub fn snapshot_round_trips() {
+    let mut game = Game::with_seed(3, 0);
+    for _ in 0..10 {
+        game.step();
+    }
+
+    let text = serialize(&game.snapshot());
+    let snapshot = parse(&text).unwrap();
+
+    assert_eq!(serialize(&snapshot), text);
+    assert_eq!(
+        snapshot.players[snapshot.current].id,
+        game.current_player().id
+    );
+    assert_eq!(snapshot.bag, *game.bag().tiles());
+    assert_eq!(snapshot.board.tiles(), game.board().tiles());
+    for (saved, player) in snapshot.players.iter().zip(game.players()) {
+        assert_eq!(saved.id, player.id);
+        assert_eq!(saved.points, player.points);
+        assert_eq!(saved.hand, player.hand);
+    }
+}
+
+#[test]
+pub fn snapshot_format_is_readable() {
+    let text = "\
+# a game about to end
+board yS@0,0 yC@1,0
+
+player 1 12 bObS
+player 2 7
+bag
+next 2
+";
+    let snapshot = parse(text).unwrap();
+
+    assert_eq!(snapshot.board.tiles().len(), 2);
+    assert!(snapshot.board.get(1, 0).is_some());
+    assert_eq!(snapshot.players.len(), 2);
+    assert_eq!(snapshot.players[0].hand.len(), 2);
+    assert!(snapshot.players[1].hand.is_empty());
+    assert!(snapshot.bag.is_empty());
+    assert_eq!(snapshot.current, 1);
+    assert_eq!(
+        serialize(&snapshot),
+        "board yS@0,0 yC@1,0\nplayer 1 12 bObS\nplayer 2 7\nbag\nnext 2\n"
+    );
+}
+
+#[test]
+pub fn invalid_snapshot_is_refused() {
+    assert_eq!(
+        parse("board yS@0,0\nboard").map(|_| ()),
+        Err(SnapshotError::Syntax(2))
+    );
+    assert_eq!(parse("player 1").map(|_| ()), Err(SnapshotError::Syntax(1)));
+    assert_eq!(
+        parse("bag xS").map(|_| ()),
+        Err(SnapshotError::Notation(
+            1,
+            NotationError::Color("x".to_owned())
+        ))
+    );
+    assert!(matches!(
+        parse("board yS@0,0 yC@0,0"),
+        Err(SnapshotError::Board(_))
+    ));
+    assert_eq!(
+        parse("player 1 0\nnext 3").map(|_| ()),
+        Err(SnapshotError::UnknownPlayer(3))
+    );
+}
+
+#[test]
+pub fn game_resumes_from_snapshot() {
+    let snapshot =
+        parse("board yS@0,0 yC@1,0\nplayer 1 3 yDbO\nplayer 2 0 rSrO\nbag gO\nnext 1").unwrap();
+    let strategies = (0..2)
+        .map(|_| Box::new(GreedyStrategy) as Box<dyn Strategy>)
+        .collect();
+    let mut game = Game::from_snapshot(snapshot, RuleSet::default(), strategies, 0);
+
+    assert_eq!(game.current_player().id, 1);
+    assert_eq!(game.scores(), vec![(1, 3), (2, 0)]);
+    let set = game.tile_set();
+
+    let result = game.step().unwrap();
+    assert_eq!(result.player_id, 1);
+    assert!(result.points > 0);
+    assert!(game.board().get(2, 0).is_some() || game.board().get(-1, 0).is_some());
+    assert_eq!(game.tile_set(), set);
+    assert_ne!(set, TileSet::full());
+}
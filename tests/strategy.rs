@@ -0,0 +1,217 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use qwirkle_solver::{
+    game::{snapshot, Game},
+    notation::parse_tiles,
+    player::action::Action,
+    rules::ruleset::RuleSet,
+    strategy::{
+        greedy::GreedyStrategy, lookahead::LookaheadStrategy, mcts::MctsStrategy, Strategy, View,
+    },
+    tile_set::TileSet,
+};
+
+/// Builds what the current player of `game` knows and passes it to `check`.
+fn with_view<T>(game: &Game, check: impl FnOnce(&View) -> T) -> T {
+    game.with_view(game.current_player().id, check).unwrap()
+}
+
+fn mcts() -> Box<dyn Strategy> {
+    Box::new(MctsStrategy {
+        iterations: 30,
+        playout_length: 4,
+        ..MctsStrategy::default()
+    })
+}
+
+#[test]
+pub fn unseen_tiles_are_in_the_bag_or_opponents_hands() {
+    let mut game = Game::with_seed(3, 5);
+    for _ in 0..7 {
+        game.step();
+    }
+
+    let mut expected = game.bag().tiles().iter().copied().collect::<TileSet>();
+    for player in game.players() {
+        if player.id != game.current_player().id {
+            expected.extend(player.hand.iter().copied());
+        }
+    }
+
+    assert_eq!(with_view(&game, |view| view.unseen_tiles()), expected);
+    assert!(game.with_view(9, |_| ()).is_none());
+}
+
+#[test]
+pub fn sampled_snapshot_is_consistent_with_the_view() {
+    let mut game = Game::with_seed(3, 8);
+    for _ in 0..5 {
+        game.step();
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let snapshot = with_view(&game, |view| view.sample_snapshot(&mut rng));
+
+    assert_eq!(snapshot.board.tiles(), game.board().tiles());
+    assert_eq!(snapshot.bag.len(), game.bag().len());
+    assert_eq!(
+        snapshot.players[snapshot.current].id,
+        game.current_player().id
+    );
+    for (sampled, player) in snapshot.players.iter().zip(game.players()) {
+        assert_eq!(sampled.id, player.id);
+        assert_eq!(sampled.points, player.points);
+        assert_eq!(sampled.hand.len(), player.hand.len());
+    }
+    assert_eq!(
+        snapshot.players[snapshot.current].hand,
+        game.current_player().hand
+    );
+
+    let sampled = Game::from_snapshot(
+        snapshot,
+        RuleSet::default(),
+        vec![
+            Box::new(GreedyStrategy),
+            Box::new(GreedyStrategy),
+            Box::new(GreedyStrategy),
+        ],
+        0,
+    );
+    assert_eq!(sampled.tile_set(), RuleSet::default().tile_set());
+}
+
+#[test]
+pub fn mcts_plays_legal_games() {
+    let rules = RuleSet::square(4);
+    let mut game = Game::with_rules(rules, vec![mcts(), Box::new(GreedyStrategy)], 3);
+
+    // illegal actions would be replaced by passes, blocking the game early
+    while game.step().is_some() {}
+
+    assert!(game.is_over());
+    assert!(game.board().tiles().len() > rules.bag_size() / 2);
+}
+
+#[test]
+pub fn mcts_with_iterations_is_reproducible() {
+    let play = |seed: u64| {
+        let mut game = Game::with_rules(RuleSet::square(4), vec![mcts(), mcts()], seed);
+        for _ in 0..6 {
+            game.step();
+        }
+
+        game.record().unwrap().clone()
+    };
+
+    assert!(play(11) == play(11));
+}
+
+fn lookahead(depth: usize) -> Box<dyn Strategy> {
+    Box::new(LookaheadStrategy {
+        depth,
+        samples: 3,
+        branching: 3,
+    })
+}
+
+#[test]
+pub fn lookahead_avoids_opening_a_qwirkle() {
+    // only squares and circles, one copy each: the opponent's last tile is known
+    let rules = RuleSet {
+        shapes_number: 2,
+        copies: 1,
+        ..RuleSet::default()
+    };
+    let board = snapshot::parse("board rS@0,0 oS@1,0 yS@2,0 gS@3,0 rO@0,1 oO@1,1 yO@2,1 gO@3,1")
+        .unwrap()
+        .board;
+    let hand = parse_tiles("bSbOpO").unwrap();
+    let view = View {
+        player_id: 1,
+        rules: &rules,
+        board: &board,
+        hand: &hand,
+        bag_size: 0,
+        scores: &[(1, 0), (2, 0)],
+        hand_sizes: &[3, 1],
+    };
+    let mut rng = StdRng::seed_from_u64(0);
+
+    // completing circles scores as much as extending both lines with blue tiles,
+    // but the latter lets the opponent complete squares with pS
+    let mut strategy = LookaheadStrategy {
+        branching: 20,
+        ..LookaheadStrategy::default()
+    };
+    let Action::Place(r#move) = strategy.choose(&view, &mut rng) else {
+        panic!("a move can be played");
+    };
+
+    assert_eq!(r#move.points, 12);
+    assert_eq!(
+        TileSet::from(r#move.combination),
+        TileSet::from(parse_tiles("bOpO").unwrap())
+    );
+}
+
+#[test]
+pub fn lookahead_ends_the_game_for_the_end_bonus() {
+    let rules = RuleSet::default();
+    // bS completes the squares, but bO can't follow it next to the other bO
+    let board = snapshot::parse("board rS@0,0 oS@1,0 yS@2,0 gS@3,0 pS@5,0 bO@4,1")
+        .unwrap()
+        .board;
+    let hand = parse_tiles("bSbO").unwrap();
+    let view = View {
+        player_id: 1,
+        rules: &rules,
+        board: &board,
+        hand: &hand,
+        bag_size: 0,
+        scores: &[(1, 0), (2, 0)],
+        hand_sizes: &[2, 6],
+    };
+    let mut rng = StdRng::seed_from_u64(0);
+
+    // completing squares scores 14 points but lets the opponent reply,
+    // while placing both tiles elsewhere scores 7 points and ends the game with the end bonus
+    let mut strategy = LookaheadStrategy {
+        branching: 20,
+        ..LookaheadStrategy::default()
+    };
+    let Action::Place(r#move) = strategy.choose(&view, &mut rng) else {
+        panic!("a move can be played");
+    };
+
+    assert_eq!(r#move.combination.len(), 2);
+    assert_eq!(r#move.points, 7);
+}
+
+#[test]
+pub fn lookahead_of_one_ply_plays_as_greedy() {
+    let play = |strategies: Vec<Box<dyn Strategy>>| {
+        let mut game = Game::with_rules(RuleSet::square(4), strategies, 4);
+        while game.step().is_some() {}
+
+        game.record().unwrap().clone()
+    };
+
+    assert!(
+        play(vec![lookahead(1), Box::new(GreedyStrategy)])
+            == play(vec![Box::new(GreedyStrategy), Box::new(GreedyStrategy)])
+    );
+}
+
+#[test]
+pub fn lookahead_plays_legal_games() {
+    for depth in [2, 3] {
+        let rules = RuleSet::square(4);
+        let mut game = Game::with_rules(rules, vec![lookahead(depth), lookahead(depth)], 9);
+
+        // illegal actions would be replaced by passes, blocking the game early
+        while game.step().is_some() {}
+
+        assert!(game.board().tiles().len() > rules.bag_size() / 2);
+    }
+}
@@ -1,116 +1,87 @@
 mod common;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 use common::format;
 use qwirkle_solver::{
     bag::Bag,
-    board::{location::Location, position::Position, Board},
+    game::snapshot::parse,
+    player::action::Action,
+    rules::ruleset::RuleSet,
     tile::{Color, Shape, Tile},
 };
 
 #[test]
 pub fn play_1() {
+    let mut rng = StdRng::seed_from_u64(0);
     let mut bag = Bag::new();
-    let mut board = Board::new();
-    common::setup_board(
-        &mut board,
-        vec![Location {
-            tile: Tile {
-                color: Color::Blue,
-                shape: Shape::Circle,
-            },
-            position: Position { x: 0, y: 0 },
-        }],
-    );
-
-    let mut player = common::create_player(vec![Tile {
-        color: Color::Blue,
-        shape: Shape::Club,
-    }]);
+    let snapshot = parse("board bO@0,0\nplayer 1 0 bC").unwrap();
+    let mut board = snapshot.board;
+    let mut player = common::create_player(snapshot.players[0].hand.clone());
 
-    player.play(&mut board, &mut bag);
+    player
+        .play(&RuleSet::default(), &mut board, &mut bag, &mut rng)
+        .unwrap();
 
     common::save("play_1", format(board, player));
 }
 
 #[test]
 pub fn play_2() {
+    let mut rng = StdRng::seed_from_u64(0);
     let mut bag = Bag::new();
-    let mut board = Board::new();
-    common::setup_board(
-        &mut board,
-        vec![Location {
-            tile: Tile {
-                color: Color::Blue,
-                shape: Shape::Circle,
-            },
-            position: Position { x: 0, y: 0 },
-        }],
-    );
-
-    let mut player = common::create_player(vec![
-        Tile {
-            color: Color::Blue,
-            shape: Shape::Diamond,
-        },
-        Tile {
-            color: Color::Blue,
-            shape: Shape::Cross,
-        },
-    ]);
+    let snapshot = parse("board bO@0,0\nplayer 1 0 bDb+").unwrap();
+    let mut board = snapshot.board;
+    let mut player = common::create_player(snapshot.players[0].hand.clone());
 
-    player.play(&mut board, &mut bag);
+    player
+        .play(&RuleSet::default(), &mut board, &mut bag, &mut rng)
+        .unwrap();
     common::save("play_2", format(board, player));
 }
 
 #[test]
 pub fn play_3() {
+    let mut rng = StdRng::seed_from_u64(0);
     let mut bag = Bag::new();
-    let mut board = Board::new();
-    common::setup_board(
-        &mut board,
-        vec![
-            Location {
-                tile: Tile {
-                    color: Color::Yellow,
-                    shape: Shape::Square,
-                },
-                position: Position { x: 0, y: 0 },
-            },
-            Location {
-                tile: Tile {
-                    color: Color::Yellow,
-                    shape: Shape::Club,
-                },
-                position: Position { x: 1, y: 0 },
-            },
-            Location {
-                tile: Tile {
-                    color: Color::Blue,
-                    shape: Shape::Club,
-                },
-                position: Position { x: 1, y: 1 },
-            },
-            Location {
-                tile: Tile {
-                    color: Color::Yellow,
-                    shape: Shape::Club,
-                },
-                position: Position { x: 2, y: 1 },
-            },
-        ],
-    );
+    let snapshot = parse("board yS@0,0 yC@1,0 bC@1,1 yC@2,1\nplayer 1 0 y+p+").unwrap();
+    let mut board = snapshot.board;
+    let mut player = common::create_player(snapshot.players[0].hand.clone());
 
-    let mut player = common::create_player(vec![
-        Tile {
-            color: Color::Yellow,
-            shape: Shape::Cross,
-        },
-        Tile {
-            color: Color::Purple,
-            shape: Shape::Cross,
-        },
-    ]);
-
-    player.play(&mut board, &mut bag);
+    player
+        .play(&RuleSet::default(), &mut board, &mut bag, &mut rng)
+        .unwrap();
     common::save("play_3", format(board, player));
 }
+
+#[test]
+pub fn exchange_keeps_best_combination() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut bag = Bag::new();
+    let snapshot = parse("board rS@0,0\nplayer 1 0 bOgOyD").unwrap();
+    let mut board = snapshot.board;
+
+    let blue_circle = Tile {
+        color: Color::Blue,
+        shape: Shape::Circle,
+    };
+    let green_circle = Tile {
+        color: Color::Green,
+        shape: Shape::Circle,
+    };
+    let yellow_diamond = Tile {
+        color: Color::Yellow,
+        shape: Shape::Diamond,
+    };
+    let mut player = common::create_player(snapshot.players[0].hand.clone());
+
+    let action = player
+        .play(&RuleSet::default(), &mut board, &mut bag, &mut rng)
+        .unwrap();
+
+    assert_eq!(action, Action::Exchange(vec![yellow_diamond]));
+    assert_eq!(player.hand.len(), 3);
+    assert!(player.hand.contains(&blue_circle));
+    assert!(player.hand.contains(&green_circle));
+    assert_eq!(bag.len(), 108);
+}
@@ -0,0 +1,103 @@
+use qwirkle_solver::{
+    board::Board,
+    game::{snapshot, Game},
+    notation::parse_tiles,
+    rules::ruleset::RuleSet,
+    strategy::{tracker::TileTracker, View},
+    tile::Tile,
+    tile_set::TileSet,
+};
+
+/// Starts a tracker for player `player_id` of `game`.
+fn tracker(game: &Game, player_id: u8) -> TileTracker {
+    game.with_view(player_id, TileTracker::new).unwrap()
+}
+
+/// Starts a tracker for player 1, holding `hand`, with `board` and `bag_size` tiles left.
+fn tracker_with(rules: &RuleSet, board: &Board, hand: &str, bag_size: usize) -> TileTracker {
+    let hand = parse_tiles(hand).unwrap();
+    let unseen = rules.tile_set().len() - board.tiles().len() - hand.len();
+
+    TileTracker::new(&View {
+        player_id: 1,
+        rules,
+        board,
+        hand: &hand,
+        bag_size,
+        scores: &[(1, 0), (2, 0)],
+        hand_sizes: &[hand.len(), unseen - bag_size],
+    })
+}
+
+fn tile(text: &str) -> Tile {
+    text.parse().unwrap()
+}
+
+#[test]
+pub fn tracker_follows_a_game_from_public_information() {
+    let mut game = Game::with_seed(3, 21);
+    let mut tracker = tracker(&game, 2);
+
+    for _ in 0..40 {
+        let Some(result) = game.step() else {
+            break;
+        };
+        tracker.observe(&result);
+
+        let mut unseen = game.bag().tiles().iter().copied().collect::<TileSet>();
+        for player in game.players() {
+            assert_eq!(tracker.hand_size(player.id), Some(player.hand.len()));
+            if player.id != 2 {
+                unseen.extend(player.hand.iter().copied());
+            }
+        }
+        assert_eq!(*tracker.unseen_tiles(), unseen);
+        assert_eq!(tracker.bag_size(), game.bag().len());
+    }
+}
+
+#[test]
+pub fn holding_probability_follows_unseen_tiles() {
+    let rules = RuleSet::default();
+    let board = snapshot::parse("board rS@0,0 rO@1,0 rD@2,0").unwrap().board;
+
+    // every unseen tile is in the opponent's hand
+    let tracker = tracker_with(&rules, &board, "rSrSrOrO", 0);
+    assert_eq!(tracker.holding_probability(2, tile("bC")), 1.);
+    assert_eq!(tracker.holding_probability(2, tile("rS")), 0.);
+    assert_eq!(tracker.holding_probability(1, tile("bC")), 0.);
+
+    // the opponent holds 6 of 101 unseen tiles, 3 of them being blue clubs
+    let tracker = tracker_with(&rules, &board, "rSrSrOrO", 95);
+    let none = (0..6)
+        .map(|i| (98 - i) as f64 / (101 - i) as f64)
+        .product::<f64>();
+    assert!((tracker.holding_probability(2, tile("bC")) - (1. - none)).abs() < 1e-9);
+    assert_eq!(tracker.unseen(tile("rD")), 2);
+    assert_eq!(tracker.available(tile("rO")), 2);
+    assert_eq!(tracker.available(tile("bC")), 3);
+}
+
+#[test]
+pub fn lines_missing_exhausted_tiles_are_dead() {
+    // one copy of each tile: the red star on the board can't complete the red line
+    let rules = RuleSet {
+        copies: 1,
+        ..RuleSet::default()
+    };
+    let board = snapshot::parse("board rS@0,0 rO@1,0 rD@2,0 rC@3,0 r*@0,1 bD@2,-1")
+        .unwrap()
+        .board;
+    let tracker = tracker_with(&rules, &board, "bS", 10);
+
+    let dead = tracker.dead_lines(&board);
+    assert_eq!(dead.len(), 2, "{dead:?}");
+    assert!(dead.iter().any(|line| line.len() == 4));
+    assert!(dead
+        .iter()
+        .any(|line| line.len() == 2 && line.locations[1].tile == tile("r*")));
+
+    // with 3 copies of each tile, every line can still be completed
+    let tracker = tracker_with(&RuleSet::default(), &board, "bS", 10);
+    assert!(tracker.dead_lines(&board).is_empty());
+}